@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, hash_map::Entry};
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 struct House {
@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 fn has_three_vowels(line: &str) -> bool {
     let re: &Regex = regex!(r"[aeiou].*[aeiou].*[aeiou]");
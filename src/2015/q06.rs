@@ -6,7 +6,7 @@ use std::{iter::repeat_n, str::FromStr};
 
 // use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 type State = Vec<[i32; 1000]>;
 
@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::{collections::HashMap, str::FromStr};
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct Wire {
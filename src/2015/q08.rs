@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 fn process_data_a(data: &str) -> i32 {
     let mut rv = 0;
@@ -6,7 +6,7 @@ use permutohedron::Heap;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 
-static INPUT: &str = include_str!("data/q09.data");
+input!("q09");
 
 fn parse(data: &str) -> (HashSet<String>, HashMap<[String; 2], usize>) {
     let re: &Regex = regex!("^([A-Za-z]+) to ([A-Za-z]+) = ([0-9]+)$");
@@ -4,7 +4,7 @@
 use regex::Regex;
 use serde_json::{Value, from_str};
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 fn process_data_a(data: &str) -> i64 {
     let numbers: &Regex = regex!(r"-?\d+");
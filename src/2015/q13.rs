@@ -9,7 +9,7 @@ use std::{
     string::ToString,
 };
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 fn parse(data: &str) -> (HashSet<String>, HashMap<Vec<String>, i32>) {
     let seating: &Regex = regex!(
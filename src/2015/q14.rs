@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::str::FromStr;
 
-static INPUT: &str = include_str!("data/q14.data");
+input!("q14");
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct Reindeer {
@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::str::FromStr;
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 #[derive(Debug)]
 struct Ingredient {
@@ -10,7 +10,7 @@ use nom::{
     multi::separated_list0,
 };
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 #[derive(Debug)]
 struct AuntSue {
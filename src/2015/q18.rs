@@ -3,7 +3,7 @@
 
 use std::{fmt, str::FromStr};
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 #[derive(Clone, Debug)]
 struct Board {
@@ -11,7 +11,7 @@ use nom::{
 };
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 
 #[derive(Clone, Debug)]
 struct Rule {
@@ -11,7 +11,7 @@ use nom::{
     sequence::terminated,
 };
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct Item {
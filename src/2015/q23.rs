@@ -1,46 +1,23 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::computer::{
-    CPU, Half, Increment, InstructionResult, Jump, JumpEven, JumpOne, Triple, parse_instructions,
-};
+use aoc::computer::{CPU, TURING_LOCK, parse_instructions, register};
 
-static INPUT: &str = include_str!("data/q23.data");
+input!("q23");
 
 fn process_data_a_impl(data: &str, reg: char) -> i64 {
-    let builders: Vec<fn(s: &str) -> InstructionResult> = vec![
-        Half::build,
-        Triple::build,
-        Increment::build,
-        Jump::build,
-        JumpEven::build,
-        JumpOne::build,
-    ];
-
-    let instructions = parse_instructions(data, &builders).unwrap().1;
-    let mut state = CPU::new(hashmap!['a' => 0, 'b' => 0], instructions);
-    while let Some(new) = state.execute() {
-        state = new;
-    }
-    state.get_register(reg)
+    let instructions = parse_instructions(data, TURING_LOCK).unwrap().1;
+    let mut state = CPU::new(instructions);
+    state.run();
+    state.get_register(register(reg).unwrap())
 }
 
 fn process_data_b_impl(data: &str, reg: char) -> i64 {
-    let builders: Vec<fn(s: &str) -> InstructionResult> = vec![
-        Half::build,
-        Triple::build,
-        Increment::build,
-        Jump::build,
-        JumpEven::build,
-        JumpOne::build,
-    ];
-
-    let instructions = parse_instructions(data, &builders).unwrap().1;
-    let mut state = CPU::new(hashmap!['a' => 1, 'b' => 0], instructions);
-    while let Some(new) = state.execute() {
-        state = new;
-    }
-    state.get_register(reg)
+    let instructions = parse_instructions(data, TURING_LOCK).unwrap().1;
+    let mut state = CPU::new(instructions);
+    state.set_register(register('a').unwrap(), 1);
+    state.run();
+    state.get_register(register(reg).unwrap())
 }
 
 fn process_data_a(data: &str) -> i64 {
@@ -4,7 +4,7 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 // static INPUT : &'static str = "1
 // 2
 // 3
@@ -4,7 +4,7 @@
 use aoc::computer::{
     parse_instructions, Half, Increment, InstructionResult, Jump, JumpEven, JumpOne, Triple, CPU,
 };
-use aoc::Day;
+use aoc::{Answer, Day};
 
 static INPUT: &str = "jio a, +16
 inc a
@@ -99,16 +99,14 @@ impl Day for Q {
         String::from("test")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let result = process_data_a(INPUT, 'b');
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let result = process_data_b(INPUT, 'b');
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
 
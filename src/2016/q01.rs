@@ -1,10 +1,10 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q01.data");
+input!("q01");
 // static INPUT : &'static str = "R2, L3";
 // static INPUT : &'static str = "R8, R4, R4, R8";
 
@@ -70,8 +70,7 @@ impl Day for Q {
         String::from("1")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut heading: Heading = Heading::North;
         let mut pos: Pos = [0, 0];
 
@@ -89,11 +88,10 @@ impl Day for Q {
             let length = handle_turn(data, &mut heading);
             run_turn(&mut pos, &heading, length);
         }
-        println!("Result = {}", pos[0].abs() + pos[1].abs());
+        Answer::from(pos[0].abs() + pos[1].abs())
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut heading: Heading = Heading::North;
         let mut pos: Pos = [0, 0];
         let mut seen = HashSet::new();
@@ -126,6 +124,6 @@ impl Day for Q {
                 break;
             }
         }
-        println!("Result = {}", pos[0].abs() + pos[1].abs());
+        Answer::from(pos[0].abs() + pos[1].abs())
     }
 }
@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 // static INPUT : &'static str = "ULL
 // RRDDD
 // LURDL
@@ -81,11 +81,11 @@ fn handle_direction(key: Key, keypad: KeypadRef, next: char) -> Key {
     direction.shift(key, keypad)
 }
 
-fn parse_line(key: &mut Key, keypad: KeypadRef, line: &str) {
+fn parse_line(key: &mut Key, keypad: KeypadRef, line: &str) -> char {
     for direction in line.chars() {
         *key = handle_direction(*key, keypad, direction);
     }
-    print!("{}", get(keypad, *key));
+    get(keypad, *key)
 }
 
 //-----------------------------------------------------
@@ -98,8 +98,7 @@ impl Day for Q {
         String::from("2")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let keypad: Keypad = vec![
             vec![' ', ' ', ' ', ' ', ' '],
             vec![' ', '1', '2', '3', ' '],
@@ -110,15 +109,14 @@ impl Day for Q {
 
         let mut key: Key = [2, 2];
 
-        print!("Result = ");
+        let mut result = String::new();
         for line in INPUT.lines() {
-            parse_line(&mut key, &keypad, line);
+            result.push(parse_line(&mut key, &keypad, line));
         }
-        println!();
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let keypad: Keypad = vec![
             vec![' ', ' ', ' ', ' ', ' ', ' ', ' '],
             vec![' ', ' ', ' ', '1', ' ', ' ', ' '],
@@ -131,10 +129,10 @@ impl Day for Q {
 
         let mut key: Key = [3, 1];
 
-        print!("Result = ");
+        let mut result = String::new();
         for line in INPUT.lines() {
-            parse_line(&mut key, &keypad, line);
+            result.push(parse_line(&mut key, &keypad, line));
         }
-        println!();
+        Answer::from(result)
     }
 }
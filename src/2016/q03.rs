@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 // static INPUT : &'static str = "5 10 25";
 // static INPUT : &'static str = "101 301 501
 // 102 302 502
@@ -28,8 +28,7 @@ impl Day for Q {
         String::from("3")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut possible = 0;
 
         fn test_data(data: &mut Vec<u32>) -> bool {
@@ -45,11 +44,10 @@ impl Day for Q {
                 possible += 1;
             }
         }
-        println!("Result = {}", possible);
+        Answer::from(possible)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut possible: u32 = 0;
 
         fn test_data(data: &mut Vec<u32>) -> bool {
@@ -81,6 +79,6 @@ impl Day for Q {
                 possible += handle_data(&mut current)
             }
         }
-        println!("Result = {}", possible);
+        Answer::from(possible)
     }
 }
@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 // static INPUT : &'static str = "aaaaa-bbb-z-y-x-123[abxyz]
 // a-b-c-d-e-f-g-h-987[abcde]
 // not-a-real-room-404[oarel]
@@ -95,8 +95,7 @@ impl Day for Q {
         String::from("4")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut sum: i32 = 0;
         for line in INPUT.lines() {
             let room: Room = line.parse().unwrap();
@@ -104,18 +103,17 @@ impl Day for Q {
                 sum += room.sector;
             }
         }
-        println!("Result = {}", sum);
+        Answer::from(sum)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         for line in INPUT.lines() {
             let room: Room = line.parse().unwrap();
             let name = room.decrypt();
             if name.contains("northpole object storage") {
-                println!("Result = \"{}\" {}", room.decrypt(), room.sector);
-                return;
+                return Answer::from(room.sector);
             }
         }
+        unreachable!("No room contains the north pole objects!");
     }
 }
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use md5::{Digest, Md5};
 
 //-----------------------------------------------------
@@ -14,9 +14,8 @@ impl Day for Q {
         String::from("5")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        print!("Result = ");
+    fn a(&self) -> Answer {
+        let mut result = String::new();
 
         let mut hasher = Md5::new();
         // let input = "abc".as_bytes();
@@ -31,20 +30,17 @@ impl Day for Q {
             let first_five =
                 i32::from(output[0]) + i32::from(output[1]) + i32::from(output[2] >> 4);
             if first_five == 0 {
-                print!("{:x}", output[2]);
+                result.push_str(&format!("{:x}", output[2]));
                 len += 1
             }
             if len == 8 {
                 break;
             }
         }
-        println!();
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        print!("Result = ");
-
+    fn b(&self) -> Answer {
         // let input = "abc".as_bytes();
         let input = b"abbhdwsy";
 
@@ -74,9 +70,10 @@ impl Day for Q {
             }
         }
 
-        for value in &password {
-            print!("{:x}", value);
-        }
-        println!();
+        let result: String = password
+            .iter()
+            .map(|value| format!("{:x}", value))
+            .collect();
+        Answer::from(result)
     }
 }
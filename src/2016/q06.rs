@@ -1,10 +1,10 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 // static INPUT : &'static str = "eedadn
 // drvtee
 // eandsr
@@ -76,8 +76,7 @@ impl Day for Q {
         String::from("6")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut frequencies: Vec<HashMap<char, i32>> = Vec::new();
         for line in INPUT.lines() {
             if frequencies.is_empty() {
@@ -92,11 +91,10 @@ impl Day for Q {
             }
         }
         let result = get_most_common(frequencies);
-        println!("Result = {:?}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut frequencies: Vec<HashMap<char, i32>> = Vec::new();
         for line in INPUT.lines() {
             if frequencies.is_empty() {
@@ -111,6 +109,6 @@ impl Day for Q {
             }
         }
         let result = get_least_common(frequencies);
-        println!("Result = {:?}", result);
+        Answer::from(result)
     }
 }
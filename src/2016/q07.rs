@@ -1,10 +1,10 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 // static INPUT : &'static str = "abba[mnop]qrst
 // abcd[bddb]xyyx
 // aaaa[qwer]tyui
@@ -74,25 +74,23 @@ impl Day for Q {
         String::from("7")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut result = 0;
         for line in INPUT.lines() {
             if is_tls(line) {
                 result += 1;
             }
         }
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut result = 0;
         for line in INPUT.lines() {
             if is_ssl(line) {
                 result += 1;
             }
         }
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
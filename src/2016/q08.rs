@@ -1,12 +1,12 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::{Day, letters::recognize_letters};
+use aoc::{Answer, Day, letters::read_letters};
 
 use regex::Regex;
 use std::fmt;
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 // static INPUT : &'static str = "rect 3x2
 // rotate column x=1 by 1
 // rotate row y=0 by 4
@@ -141,8 +141,7 @@ impl Day for Q {
         String::from("8")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut display = Display::new();
         // println!("{:?}", display);
         for line in INPUT.lines() {
@@ -150,11 +149,10 @@ impl Day for Q {
             display.run(&turn);
             // println!("{:?}", display);
         }
-        println!("Result = {}", display.on());
+        Answer::from(display.on())
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut display = Display::new();
         for line in INPUT.lines() {
             let turn: Turn = line.parse().unwrap();
@@ -169,6 +167,6 @@ impl Day for Q {
         }
 
         // println!("\n{:?}", display);
-        println!("Result = {}", recognize_letters(&letters));
+        Answer::from(read_letters(&letters, COLS))
     }
 }
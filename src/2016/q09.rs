@@ -1,10 +1,10 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q09.data");
+input!("q09");
 // static INPUT : &'static str = "ADVENT
 // A(1x5)BC
 // (3x3)XYZ
@@ -78,23 +78,21 @@ impl Day for Q {
         String::from("9")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut result = 0;
         for line in INPUT.lines() {
             let output = decompress(line);
             result += output.len();
         }
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut result = 0;
         for line in INPUT.lines() {
             let output = double_decompress(line);
             result += output.len();
         }
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use regex::Regex;
 use std::{collections::HashMap, str::FromStr};
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 // static INPUT : &'static str = "value 5 goes to bot 2
 // bot 2 gives low to bot 1 and high to bot 0
 // value 3 goes to bot 1
@@ -196,8 +196,7 @@ impl Day for Q {
         String::from("10")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut values: Vec<Value> = Vec::new();
         let mut bots: HashMap<i32, Bot> = HashMap::new();
         for line in INPUT.lines() {
@@ -230,11 +229,10 @@ impl Day for Q {
         let result = bots.values().find(|bot|
       // bot.first == Some(2) && bot.second == Some(5)
       bot.first == Some(17) && bot.second == Some(61));
-        println!("Result = Bot {:?}", result.unwrap().number);
+        Answer::from(result.unwrap().number)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut values: Vec<Value> = Vec::new();
         let mut bots: HashMap<i32, Bot> = HashMap::new();
         for line in INPUT.lines() {
@@ -266,9 +264,6 @@ impl Day for Q {
         }
         // println!("\n  O:{:?}", outputs);
         let result = outputs[&0] * outputs[&1] * outputs[&2];
-        println!(
-            "Result = {:?}*{:?}*{:?} = {:?}",
-            outputs[&0], outputs[&1], outputs[&2], result
-        );
+        Answer::from(result)
     }
 }
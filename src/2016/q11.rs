@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use regex::Regex;
 use std::{cmp::Ordering, fmt, str::FromStr};
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum Item {
@@ -360,13 +360,11 @@ impl Day for Q {
         String::from("11")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        println!("Result = {}", get_result(INPUT));
+    fn a(&self) -> Answer {
+        Answer::from(get_result(&INPUT))
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         // Add to the first floor:
         //     An elerium generator.
         //     An elerium-compatible microchip.
@@ -374,7 +372,7 @@ impl Day for Q {
         //     A dilithium-compatible microchip.
 
         // Too slow.
-        // println!("Result = {}", get_result(INPUT));
-        println!("Result = 61");
+        // Answer::from(get_result(&INPUT))
+        Answer::from(61)
     }
 }
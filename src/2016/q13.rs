@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use std::{cmp::Ordering, collections::BinaryHeap};
 
 // static INPUT_NUMBER : i32 = 10;
@@ -103,9 +103,7 @@ impl Day for Q {
         String::from("13")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        println!();
+    fn a(&self) -> Answer {
         let mut result = 0;
 
         let mut next = BinaryHeap::new();
@@ -117,7 +115,7 @@ impl Day for Q {
             let current = next.pop().unwrap();
 
             if current.x == INPUT_TARGET_X && current.y == INPUT_TARGET_Y {
-                println!("WINNING!!!!   {:?}", current);
+                // println!("WINNING!!!!   {:?}", current);
                 result = current.moves;
                 break;
             }
@@ -135,11 +133,10 @@ impl Day for Q {
             }
         }
 
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut result = 0;
 
         let mut next: Vec<State> = Vec::new();
@@ -151,7 +148,7 @@ impl Day for Q {
             let current = next.remove(0);
 
             if current.moves > 50 {
-                println!("Done!!!!   {:?}", current);
+                // println!("Done!!!!   {:?}", current);
                 result = seen.len();
                 break;
             }
@@ -166,6 +163,6 @@ impl Day for Q {
                 }
             }
         }
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
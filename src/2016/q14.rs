@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use md5::{Digest, Md5};
 
 use regex::Regex;
@@ -126,9 +126,7 @@ impl Day for Q {
         String::from("14")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-
+    fn a(&self) -> Answer {
         let mut keys: Keys = Vec::new();
         let mut quintuples = HashMap::new();
 
@@ -150,12 +148,12 @@ impl Day for Q {
             get_quintuple(&out_string, &mut keys, &mut quintuples, i);
             i += 1;
         }
-        println!("Result = {:?}", keys[63]);
+        match &keys[63] {
+            Key::Potential(index) | Key::Confirmed(index, _) => Answer::from(*index),
+        }
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-
+    fn b(&self) -> Answer {
         let mut keys: Keys = Vec::new();
         let mut quintuples = HashMap::new();
 
@@ -178,6 +176,8 @@ impl Day for Q {
             get_quintuple(&out_string, &mut keys, &mut quintuples, i);
             i += 1;
         }
-        println!("Result = {:?}", keys[63]);
+        match &keys[63] {
+            Key::Potential(index) | Key::Confirmed(index, _) => Answer::from(*index),
+        }
     }
 }
@@ -1,13 +1,13 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use regex::Regex;
 use std::str::FromStr;
 
 // static INPUT : &'static str = "Disc #1 has 5 positions; at time=0, it is at position 4.
 // Disc #2 has 2 positions; at time=0, it is at position 1.";
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 #[derive(Clone, Debug)]
 struct Disc {
@@ -78,15 +78,13 @@ impl Day for Q {
         String::from("15")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        println!("Result = {}", get_result(INPUT));
+    fn a(&self) -> Answer {
+        Answer::from(get_result(&INPUT))
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let input =
             INPUT.to_owned() + "\nDisc #7 has 11 positions; at time=0, it is at position 0.";
-        println!("Result = {}", get_result(&input));
+        Answer::from(get_result(&input))
     }
 }
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 // static INPUT : &'static str = "10000";
 // static LENGTH_A : usize = 20;
@@ -59,13 +59,11 @@ impl Day for Q {
         String::from("16")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        println!("Result = {}", get_result(INPUT, LENGTH_A));
+    fn a(&self) -> Answer {
+        Answer::from(get_result(INPUT, LENGTH_A))
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        println!("Result = {}", get_result(INPUT, LENGTH_B));
+    fn b(&self) -> Answer {
+        Answer::from(get_result(INPUT, LENGTH_B))
     }
 }
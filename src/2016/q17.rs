@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use md5::{Digest, Md5};
 use std::{cmp::Ordering, collections::BinaryHeap};
 
@@ -123,8 +123,7 @@ impl Day for Q {
         String::from("17")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut result = String::from("No path found.");
         let mut next = BinaryHeap::new();
         next.push(State::new("", 0, 0));
@@ -140,11 +139,10 @@ impl Day for Q {
             }
         }
         // println!("{:?}", next);
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut result = 0;
         let mut next = BinaryHeap::new();
         next.push(State::new("", 0, 0));
@@ -159,6 +157,6 @@ impl Day for Q {
             }
         }
         // println!("{:?}", next);
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
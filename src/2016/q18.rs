@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use std::str::FromStr;
 
 // static INPUT : &'static str = "..^^.";
@@ -89,13 +89,11 @@ impl Day for Q {
         String::from("18")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        println!("Result = {}", get_result(LENGTH_A));
+    fn a(&self) -> Answer {
+        Answer::from(get_result(LENGTH_A))
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        println!("Result = {}", get_result(LENGTH_B));
+    fn b(&self) -> Answer {
+        Answer::from(get_result(LENGTH_B))
     }
 }
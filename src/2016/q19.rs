@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 // static INPUT : usize = 5;
 static INPUT: usize = 3_014_603;
@@ -40,13 +40,11 @@ impl Day for Q {
         String::from("19")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        println!("Result = {}", get_result_a());
+    fn a(&self) -> Answer {
+        Answer::from(get_result_a())
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        println!("Result = {}", get_result_b());
+    fn b(&self) -> Answer {
+        Answer::from(get_result_b())
     }
 }
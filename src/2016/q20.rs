@@ -1,13 +1,13 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use std::ops::Range;
 
 // static INPUT : &'static str = "5-8
 // 0-2
 // 4-7";
-static INPUT: &str = include_str!("data/q20.data");
+input!("q20");
 
 fn get_ranges() -> Vec<Range<u32>> {
     let mut ranges = Vec::new();
@@ -64,13 +64,11 @@ impl Day for Q {
         String::from("20")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        println!("Result = {}", get_first_allowed());
+    fn a(&self) -> Answer {
+        Answer::from(get_first_allowed())
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        println!("Result = {}", get_num_allowed());
+    fn b(&self) -> Answer {
+        Answer::from(get_num_allowed())
     }
 }
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use regex::Regex;
 use std::str::FromStr;
 
@@ -14,7 +14,7 @@ use std::str::FromStr;
 // move position 3 to position 0
 // rotate based on position of letter b
 // rotate based on position of letter d";
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 static PASSCODE: &str = "abcdefgh";
 static SCRAMBLED: &str = "fbgdceah";
 
@@ -214,8 +214,7 @@ impl Day for Q {
         String::from("21")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut instructions: Vec<Instruction> = Vec::new();
         for line in INPUT.lines() {
             let instruction = line.parse().unwrap();
@@ -226,11 +225,10 @@ impl Day for Q {
         for instruction in instructions {
             rv = instruction.execute(&rv);
         }
-        println!("Result = {}", rv);
+        Answer::from(rv)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut instructions: Vec<Instruction> = Vec::new();
         for line in INPUT.lines() {
             let instruction = line.parse().unwrap();
@@ -242,6 +240,6 @@ impl Day for Q {
         for instruction in &instructions {
             rv = instruction.unexecute(&rv);
         }
-        println!("Result = {}", rv);
+        Answer::from(rv)
     }
 }
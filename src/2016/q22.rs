@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use regex::Regex;
 use std::str::FromStr;
 
@@ -15,7 +15,7 @@ use std::str::FromStr;
 // /dev/grid/node-x2-y0   10T    6T     4T   60%
 // /dev/grid/node-x2-y1    9T    8T     1T   88%
 // /dev/grid/node-x2-y2    9T    6T     3T   66%";
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct Node {
@@ -59,8 +59,7 @@ impl Day for Q {
         String::from("22")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut nodes = Vec::new();
         for line in INPUT.lines() {
             let node: Node = line.parse().unwrap();
@@ -80,13 +79,12 @@ impl Day for Q {
                 }
             }
         }
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         // From https://codepen.io/anon/pen/BQEZzK and manual solving.
         let result = 213;
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use regex::Regex;
 use std::{
@@ -17,7 +17,7 @@ use std::{
 // #.#######.#
 // #4.......3#
 // ###########";
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 #[derive(Clone, Eq, PartialEq)]
 enum Direction {
@@ -273,11 +273,10 @@ impl Day for Q {
         String::from("24")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let mut result = usize::MAX;
         let mut locations = Vec::new();
-        let board = get_board(INPUT, &mut locations);
+        let board = get_board(&INPUT, &mut locations);
         let mut distances = HashMap::new();
 
         for i in 0..locations.len() {
@@ -300,19 +299,18 @@ impl Day for Q {
             }
             if total_distance < result {
                 let display: Vec<i32> = perm.iter().map(|item| item.loc).collect();
-                println!("Perm: {:?} => {} < {}", display, total_distance, result);
+                // println!("Perm: {:?} => {} < {}", display, total_distance, result);
                 result = total_distance;
             }
         }
 
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let mut result = usize::MAX;
         let mut locations = Vec::new();
-        let board = get_board(INPUT, &mut locations);
+        let board = get_board(&INPUT, &mut locations);
         let mut distances = HashMap::new();
 
         for i in 0..locations.len() {
@@ -336,10 +334,10 @@ impl Day for Q {
             }
             if total_distance < result {
                 let display: Vec<i32> = perm.iter().map(|item| item.loc).collect();
-                println!("Perm: {:?} => {} < {}", display, total_distance, result);
+                // println!("Perm: {:?} => {} < {}", display, total_distance, result);
                 result = total_distance;
             }
         }
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
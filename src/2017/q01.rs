@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
-static INPUT: &str = include_str!("data/q01.data");
+input!("q01");
 
 fn process_data(line: &str, offset: usize) -> u32 {
     let mut rv = 0;
@@ -27,16 +27,14 @@ impl Day for Q {
         String::from("1")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data(INPUT, 1);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data(&INPUT, 1);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data(INPUT, INPUT.len() / 2);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data(&INPUT, INPUT.len() / 2);
+        Answer::from(result)
     }
 }
 
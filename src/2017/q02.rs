@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 
 fn process_data_a(data: &str) -> i32 {
     let mut rv = 0;
@@ -47,16 +47,14 @@ impl Day for Q {
         String::from("2")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
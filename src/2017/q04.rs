@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 fn process_data_a(data: &str) -> i32 {
     let mut rv = 0;
@@ -49,16 +49,14 @@ impl Day for Q {
         String::from("4")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 fn parse(data: &str) -> Vec<i32> {
     data.lines().map(|i| i.parse::<i32>().unwrap()).collect()
@@ -46,16 +46,14 @@ impl Day for Q {
         String::from("5")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
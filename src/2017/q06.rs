@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use std::collections::{HashMap, HashSet};
 
@@ -65,16 +65,14 @@ impl Day for Q {
         String::from("6")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
 
@@ -1,12 +1,12 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use regex::Regex;
 use std::{collections::HashMap, str::FromStr, string::ToString};
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 #[derive(Clone, Debug)]
 struct Disc {
@@ -129,16 +129,14 @@ impl Day for Q {
         String::from("7")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
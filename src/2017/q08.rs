@@ -1,12 +1,12 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use regex::Regex;
 use std::{collections::HashMap, str::FromStr};
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 #[derive(Clone, Debug)]
 enum Operation {
@@ -186,16 +186,14 @@ impl Day for Q {
         String::from("8")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result.values().max().unwrap());
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(*result.values().max().unwrap())
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result.1);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result.1)
     }
 }
 
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use itertools::Itertools;
 
@@ -55,22 +55,17 @@ impl Day for Q {
         String::from("10")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let input: Vec<usize> = INPUT
             .split(',')
             .map(|x| x.parse::<usize>().unwrap())
             .collect();
         let result = process_data(256, &input, 1);
-        println!("Result = {}", result[0] * result[1]);
+        Answer::from(result[0] * result[1])
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        println!(
-            "Result = {}",
-            densify(&process_data(256, &get_input(INPUT), 64))
-        );
+    fn b(&self) -> Answer {
+        Answer::from(densify(&process_data(256, &get_input(INPUT), 64)))
     }
 }
 
@@ -1,12 +1,12 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use once_cell::sync::Lazy;
 
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 #[derive(Debug)]
 struct HexPosition {
@@ -81,16 +81,14 @@ impl Day for Q {
         String::from("11")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
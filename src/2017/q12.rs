@@ -1,12 +1,12 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use regex::Regex;
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 fn parse_lines(data: &str) -> Vec<HashSet<u32>> {
     let mut rv: Vec<HashSet<u32>> = Vec::new();
@@ -48,16 +48,14 @@ impl Day for Q {
         String::from("12")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use itertools::{Itertools, enumerate};
 
@@ -124,16 +124,14 @@ impl Day for Q {
         String::from("14")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
+    fn a(&self) -> Answer {
         let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
 
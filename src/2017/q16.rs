@@ -1,12 +1,12 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use regex::Regex;
 use std::{iter::FromIterator, str::FromStr};
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 #[derive(Debug)]
 enum Instruction {
@@ -120,16 +120,14 @@ impl Day for Q {
         String::from("16")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(16, INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(16, &INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(16, INPUT, 1_000_000_000);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(16, &INPUT, 1_000_000_000);
+        Answer::from(result)
     }
 }
 
@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use std::iter::FromIterator;
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 
 #[derive(Clone, Debug, PartialEq)]
 enum Cell {
@@ -180,16 +180,14 @@ impl Day for Q {
         String::from("19")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
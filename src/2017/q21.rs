@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use std::{collections::HashSet, str::FromStr};
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 #[derive(Debug, Eq, Clone, Hash, PartialEq)]
 struct Rule {
@@ -159,16 +159,14 @@ impl Day for Q {
         String::from("21")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data(INPUT, 5);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data(&INPUT, 5);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data(INPUT, 18);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data(&INPUT, 18);
+        Answer::from(result)
     }
 }
 
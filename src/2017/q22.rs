@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use std::{collections::HashMap, str::FromStr};
 
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 #[derive(Debug)]
 enum Direction {
@@ -203,16 +203,14 @@ impl Day for Q {
         String::from("22")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT, 10_000);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT, 10_000);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT, 10_000_000);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT, 10_000_000);
+        Answer::from(result)
     }
 }
 
@@ -1,246 +1,26 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::computer::{CPU, duet::COPROCESSOR, parse_instructions};
+use aoc::{Answer, Day};
 
-use regex::Regex;
-use std::{collections::HashMap, str::FromStr};
+input!("q23");
 
-static INPUT: &str = include_str!("data/q23.data");
-
-#[derive(Clone, Debug)]
-enum Instruction {
-    SetReg(char, char),
-    SetLit(char, i64),
-    SubReg(char, char),
-    SubLit(char, i64),
-    MulReg(char, char),
-    MulLit(char, i64),
-    JumpRegReg(char, char),
-    JumpRegLit(char, i64),
-    JumpLitReg(i64, char),
-    JumpLitLit(i64, i64),
-}
-
-impl Instruction {
-    fn execute(&self, state: &State) -> State {
-        let mut rv = state.clone();
-        match (*self).clone() {
-            Instruction::SetReg(dst, src) => {
-                rv.pc += 1;
-                rv.registers.insert(dst, state.registers[&src]);
-            }
-            Instruction::SetLit(reg, lit) => {
-                rv.pc += 1;
-                rv.registers.insert(reg, lit);
-            }
-            Instruction::SubReg(dst, src) => {
-                rv.pc += 1;
-                rv.registers
-                    .insert(dst, state.registers[&dst] - state.registers[&src]);
-            }
-            Instruction::SubLit(reg, lit) => {
-                rv.pc += 1;
-                rv.registers.insert(reg, state.registers[&reg] - lit);
-            }
-            Instruction::MulReg(dst, src) => {
-                rv.pc += 1;
-                rv.registers
-                    .insert(dst, state.registers[&dst] * state.registers[&src]);
-            }
-            Instruction::MulLit(reg, lit) => {
-                rv.pc += 1;
-                rv.registers.insert(reg, state.registers[&reg] * lit);
-            }
-            Instruction::JumpRegReg(reg_test, reg_offset) => {
-                if rv.registers[&reg_test] != 0 {
-                    rv.pc += rv.registers[&reg_offset];
-                } else {
-                    rv.pc += 1;
-                }
-            }
-            Instruction::JumpRegLit(reg, offset) => {
-                if rv.registers[&reg] != 0 {
-                    rv.pc += offset;
-                } else {
-                    rv.pc += 1;
-                }
-            }
-            Instruction::JumpLitReg(test, reg) => {
-                if test != 0 {
-                    rv.pc += rv.registers[&reg];
-                } else {
-                    rv.pc += 1;
-                }
-            }
-            Instruction::JumpLitLit(test, offset) => {
-                if test != 0 {
-                    rv.pc += offset;
-                } else {
-                    rv.pc += 1;
-                }
-            }
-        }
-        rv
-    }
-
-    fn registers(&self) -> Vec<char> {
-        match (*self).clone() {
-            Instruction::SetLit(reg, _)
-            | Instruction::SubLit(reg, _)
-            | Instruction::MulLit(reg, _)
-            | Instruction::JumpRegLit(reg, _)
-            | Instruction::JumpLitReg(_, reg) => vec![reg],
-            Instruction::JumpLitLit(_, _) => vec![],
-            Instruction::SetReg(a, b)
-            | Instruction::SubReg(a, b)
-            | Instruction::MulReg(a, b)
-            | Instruction::JumpRegReg(a, b) => vec![a, b],
-        }
-    }
-}
-
-impl FromStr for Instruction {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Instruction, ()> {
-        let set_reg_re: &Regex = regex!(r"^ *set ([a-z]) ([a-z])( *=>.*)?$");
-        let set_lit_re: &Regex = regex!(r"^ *set ([a-z]) (-?\d+)( *=>.*)?$");
-        let sub_reg_re: &Regex = regex!(r"^ *sub ([a-z]) ([a-z])( *=>.*)?$");
-        let sub_lit_re: &Regex = regex!(r"^ *sub ([a-z]) (-?\d+)( *=>.*)?$");
-        let mul_reg_re: &Regex = regex!(r"^ *mul ([a-z]) ([a-z])( *=>.*)?$");
-        let mul_lit_re: &Regex = regex!(r"^ *mul ([a-z]) (-?\d+)( *=>.*)?$");
-        let jump_litlit_re: &Regex = regex!(r"^ *jnz (-?[0-9]+) (-?[0-9]+)( *=>.*)?$");
-        let jump_litreg_re: &Regex = regex!(r"^ *jnz (-?[0-9]+) ([a-z])( *=>.*)?$");
-        let jump_reglit_re: &Regex = regex!(r"^ *jnz ([a-z]) (-?[0-9]+)( *=>.*)?$");
-        let jump_regreg_re: &Regex = regex!(r"^ *jnz ([a-z]) ([a-z])( *=>.*)?$");
-
-        if let Some(cap) = set_reg_re.captures(s) {
-            return Ok(Instruction::SetReg(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = set_lit_re.captures(s) {
-            return Ok(Instruction::SetLit(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = sub_reg_re.captures(s) {
-            return Ok(Instruction::SubReg(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = sub_lit_re.captures(s) {
-            return Ok(Instruction::SubLit(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = mul_reg_re.captures(s) {
-            return Ok(Instruction::MulReg(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = mul_lit_re.captures(s) {
-            return Ok(Instruction::MulLit(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = jump_regreg_re.captures(s) {
-            return Ok(Instruction::JumpRegReg(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = jump_reglit_re.captures(s) {
-            return Ok(Instruction::JumpRegLit(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = jump_litreg_re.captures(s) {
-            return Ok(Instruction::JumpLitReg(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        if let Some(cap) = jump_litlit_re.captures(s) {
-            return Ok(Instruction::JumpLitLit(
-                cap[1].parse().unwrap(),
-                cap[2].parse().unwrap(),
-            ));
-        }
-
-        println!("Unknown instruction! '{}'", s);
-        Err(())
-    }
-}
-
-#[derive(Clone, Debug)]
-struct State {
-    registers: HashMap<char, i64>,
-    pc: i64,
-    _incoming: Vec<i64>,
-    _outgoing: Vec<i64>,
-    instructions: Vec<Instruction>,
-    _waiting: bool,
-}
-
-impl State {
-    pub fn new(instructions: Vec<Instruction>, registers: HashMap<char, i64>) -> State {
-        State {
-            registers,
-            pc: 0,
-            _incoming: Vec::new(),
-            _outgoing: Vec::new(),
-            instructions,
-            _waiting: false,
-        }
-    }
-
-    fn execute(&self) -> State {
-        let instruction = &self.instructions[self.pc as usize];
-        instruction.execute(self)
-    }
-}
-
-fn process_data_a(data: &str) -> i64 {
-    let mut instructions: Vec<Instruction> = Vec::new();
-    let mut registers: HashMap<char, i64> = HashMap::new();
-    for line in data.lines() {
-        let instruction: Instruction = line.parse().unwrap();
-        instructions.push(instruction.clone());
-        for reg in instruction.registers() {
-            registers.insert(reg, 0);
-        }
-    }
-    // println!("{:?}, {:?}", registers, instructions);
-    let mut state = State::new(instructions, registers);
+fn process_data_a(data: &str) -> usize {
+    let instructions = parse_instructions(data, COPROCESSOR).unwrap().1;
+    let mut cpu = CPU::new(instructions);
     let mut value = 0;
-    while (state.pc as usize) < state.instructions.len() {
-        value += match state.instructions[state.pc as usize] {
-            Instruction::MulLit(_, _) | Instruction::MulReg(_, _) => 1,
-            _ => 0,
-        };
-        state = state.execute();
+    while let Some(instruction) = cpu.next_instruction() {
+        if instruction.name() == "mul" {
+            value += 1;
+        }
+        cpu.step();
     }
     value
 }
 
+// Part B's program counts the numbers from 105,700 to 122,700 in steps of 17 that aren't
+// prime, far too slowly to run, so it's worked out from this table instead.
 fn process_data_b() -> i64 {
     let primes = vec![
         105_701, 105_727, 105_733, 105_751, 105_761, 105_767, 105_769, 105_817, 105_829, 105_863,
@@ -411,16 +191,14 @@ impl Day for Q {
         String::from("23")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
+    fn b(&self) -> Answer {
         let result = process_data_b();
-        println!("Result = {}", result);
+        Answer::from(result)
     }
 }
 
@@ -429,6 +207,15 @@ fn a() {
     use pretty_assertions::assert_eq;
 
     assert_eq!(process_data_a(""), 0);
+    assert_eq!(
+        process_data_a(
+            "set b 2
+mul a b
+sub b 1
+jnz b -2"
+        ),
+        2
+    );
 }
 
 #[test]
@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 
 use std::{cmp::Ordering, collections::BinaryHeap, str::FromStr};
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct Pipe {
@@ -161,16 +161,14 @@ impl Day for Q {
         String::from("24")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
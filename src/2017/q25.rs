@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::Day;
+use aoc::{Answer, Day};
 use nom::{
     IResult, Parser,
     branch::alt,
@@ -15,7 +15,7 @@ use std::{
     str,
 };
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct Action {
@@ -168,16 +168,14 @@ impl Day for Q {
         String::from("25")
     }
 
-    fn a(&self) {
-        print!("{}A: ", self.number());
-        let result = process_data_a(INPUT);
-        println!("Result = {}", result);
+    fn a(&self) -> Answer {
+        let result = process_data_a(&INPUT);
+        Answer::from(result)
     }
 
-    fn b(&self) {
-        print!("{}B: ", self.number());
-        let result = process_data_b(INPUT);
-        println!("Result = {}", result);
+    fn b(&self) -> Answer {
+        let result = process_data_b(&INPUT);
+        Answer::from(result)
     }
 }
 
@@ -8,7 +8,7 @@ use std::{
     str::FromStr,
 };
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 #[derive(Debug)]
 struct Square {
@@ -5,7 +5,7 @@ use chrono::NaiveDate;
 use regex::Regex;
 use std::{collections::HashMap, str::FromStr};
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 #[derive(Clone, Debug)]
 enum Record {
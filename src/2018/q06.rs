@@ -8,7 +8,7 @@ use std::{
     str::FromStr,
 };
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 #[derive(Clone, Debug)]
 struct Point {
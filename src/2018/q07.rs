@@ -8,7 +8,7 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 static RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"Step ([A-Z]) must be finished before step ([A-Z]) can begin.").unwrap()
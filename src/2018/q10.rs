@@ -1,13 +1,14 @@
 //-----------------------------------------------------
 // Setup.
 
+use aoc::letters::read_letters;
 use regex::Regex;
 use std::{
     cmp::{max, min},
     str::FromStr,
 };
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 #[derive(Debug)]
 struct Point {
@@ -48,7 +49,8 @@ impl Point {
     }
 }
 
-fn process_data_a(data: &str) -> String {
+/// Move the points until they spell something, and return the picture they make.
+fn message(data: &str) -> (Vec<bool>, usize) {
     let mut points = vec![];
     for line in data.lines() {
         let point: Point = line.parse().unwrap();
@@ -85,26 +87,24 @@ fn process_data_a(data: &str) -> String {
                 max_y = max(max_y, point.p_y);
             }
 
-            let mut rv = String::with_capacity(area as usize * 2 + max_y as usize + 1);
-            rv.push('\n');
-            for y in min_y - 1..=max_y + 1 {
-                'x: for x in min_x - 1..=max_x + 1 {
-                    for point in &points {
-                        if point.p_x == x && point.p_y == y {
-                            rv.push('█');
-                            continue 'x;
-                        }
-                    }
-                    rv.push(' ');
+            let width = (max_x - min_x + 1) as usize;
+            let mut image = vec![];
+            for y in min_y..=max_y {
+                for x in min_x..=max_x {
+                    image.push(points.iter().any(|point| point.p_x == x && point.p_y == y));
                 }
-                rv.push('\n');
             }
-            break rv;
+            break (image, width);
         }
         smallest = area;
     }
 }
 
+fn process_data_a(data: &str) -> String {
+    let (image, width) = message(data);
+    read_letters(&image, width)
+}
+
 fn process_data_b(data: &str) -> i32 {
     let mut points = vec![];
     for line in data.lines() {
@@ -145,11 +145,11 @@ q_impl!("10");
 
 #[test]
 fn a() {
+    use aoc::letters::draw_image;
     use pretty_assertions::assert_eq;
 
-    assert_eq!(
-        process_data_a(
-            "position=< 9,  1> velocity=< 0,  2>
+    let (image, width) = message(
+        "position=< 9,  1> velocity=< 0,  2>
 position=< 7,  0> velocity=<-1,  0>
 position=< 3, -2> velocity=<-1,  1>
 position=< 6, 10> velocity=<-2, -1>
@@ -179,19 +179,18 @@ position=< 5,  0> velocity=< 1,  0>
 position=<-6,  0> velocity=< 2,  0>
 position=< 5,  9> velocity=< 1, -2>
 position=<14,  7> velocity=<-2,  0>
-position=<-3,  6> velocity=< 2, -1>"
-        ),
-        "
-            
- █   █  ███ 
- █   █   █  
- █   █   █  
- █████   █  
- █   █   █  
- █   █   █  
- █   █   █  
- █   █  ███ 
-            
+position=<-3,  6> velocity=< 2, -1>",
+    );
+    assert_eq!(
+        draw_image(&image, width),
+        "█   █  ███
+█   █   █ 
+█   █   █ 
+█████   █ 
+█   █   █ 
+█   █   █ 
+█   █   █ 
+█   █  ███
 "
     );
 }
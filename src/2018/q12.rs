@@ -10,7 +10,7 @@ use std::{
     str::{FromStr, Lines},
 };
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct State {
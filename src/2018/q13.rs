@@ -8,7 +8,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 #[derive(Clone, Debug)]
 enum Direction {
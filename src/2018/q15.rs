@@ -9,7 +9,7 @@ use std::{
     str::FromStr,
 };
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 #[derive(Clone, Debug)]
 enum Direction {
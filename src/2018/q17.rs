@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q17.data");
+input!("q17");
 
 #[derive(Debug)]
 struct Board {
@@ -7,7 +7,7 @@ use std::{
     str::FromStr,
 };
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 struct Board {
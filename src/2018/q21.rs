@@ -3,7 +3,7 @@
 
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 fn step(input: i64) -> i64 {
     let init = 707_129;
@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::str::FromStr;
 
-static INPUT: &str = include_str!("data/q23.data");
+input!("q23");
 
 #[derive(Debug)]
 struct Bot {
@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::{borrow::ToOwned, collections::BTreeMap, str::Lines};
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 #[derive(Clone, Copy, Debug)]
 enum Groups {
@@ -3,7 +3,7 @@
 
 use std::{collections::HashMap, str::FromStr};
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 #[derive(Clone, Debug)]
 struct Point {
@@ -1,20 +1,36 @@
 use core::fmt;
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    ops::Index,
+};
 
 use custom_error::custom_error;
+use itertools::Itertools;
+use serde_json::{Value, json};
 
 custom_error! {
     #[derive(PartialEq, Eq, PartialOrd)]
     pub IntcodeError
-        ModeNotFound {flag: i128} = "Mode {flag} not understood.",
-        OpcodeNotFound {opcode: i128} = "Opcode {opcode} not understood",
+        ModeNotFound {position: usize, opcode: i128} = "Instruction {opcode} at {position} has a mode that isn't understood",
+        OpcodeNotFound {position: usize, opcode: i128} = "Opcode {opcode} at {position} not understood",
+        TruncatedInstruction {position: usize, opcode: i128} = "Instruction {opcode} at {position} runs off the end of the tape",
+        ImmediateWrite {position: usize, opcode: i128} = "Instruction {opcode} at {position} writes to an immediate parameter",
+        Overflow {position: usize, opcode: i128} = "Instruction {opcode} at {position} overflowed",
         MachineHalted = "Attempting to run halted machine.",
         MissingValue = "Missing value.",
         MachineNotWaiting = "Machine should be waiting for input, but isn't",
-        InvalidPosition {position: usize, len: usize} = "Invalid position {position}, should be less than {len}",
+        InvalidPosition {position: usize, opcode: i128, target: usize, len: usize} = "Instruction {opcode} at {position} moves on to {target}, past the end of the tape at {len}",
+        InvalidAddress {position: usize, opcode: i128, address: i128} = "Instruction {opcode} at {position} uses invalid address {address}",
+        InvalidJump {position: usize, opcode: i128, target: i128} = "Instruction {opcode} at {position} jumps to invalid address {target}",
+        UnknownMachine {name: String} = "No machine called {name} in the network",
+        AssemblyError {line: usize, message: String} = "Line {line}: {message}",
         MachineExceededLimit {limit: usize}= "Machine exceeded run length limit of {limit}",
 }
 
+/// Addresses this far out are treated as a bug in the program,
+/// rather than growing memory to reach them.
+const MAX_ADDRESS: usize = 1 << 20;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum State {
     Ready,
@@ -22,7 +38,7 @@ pub enum State {
     Halted,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Mode {
     Immediate,
     Position,
@@ -30,17 +46,58 @@ enum Mode {
 }
 
 impl Mode {
-    fn get_mode(flag: i128) -> Result<Mode, IntcodeError> {
+    fn get_mode(flag: i128) -> Option<Mode> {
         match flag % 10 {
-            0 => Ok(Mode::Position),
-            1 => Ok(Mode::Immediate),
-            2 => Ok(Mode::Relative),
-            _ => Err(IntcodeError::ModeNotFound { flag }),
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+
+    /// The digit that selects this mode in an instruction.
+    fn flag(self) -> i128 {
+        match self {
+            Mode::Position => 0,
+            Mode::Immediate => 1,
+            Mode::Relative => 2,
         }
     }
+
+    /// Render a parameter the way the disassembler shows it: `5`, `[5]`, or `[rb+5]`.
+    fn operand(self, value: i128) -> String {
+        match self {
+            Mode::Immediate => value.to_string(),
+            Mode::Position => format!("[{}]", value),
+            Mode::Relative => format!("[rb{:+}]", value),
+        }
+    }
+}
+
+/// An instruction as the assembler and disassembler see it.
+struct Instruction {
+    name: &'static str,
+    code: i128,
+    parameters: usize,
+    /// The parameter the instruction writes to, which can't be immediate.
+    writes: Option<usize>,
 }
 
-#[derive(Debug)]
+#[rustfmt::skip]
+const INSTRUCTIONS: &[Instruction] = &[
+    Instruction { name: "add", code: 1, parameters: 3, writes: Some(2) },
+    Instruction { name: "mul", code: 2, parameters: 3, writes: Some(2) },
+    Instruction { name: "in", code: 3, parameters: 1, writes: Some(0) },
+    Instruction { name: "out", code: 4, parameters: 1, writes: None },
+    Instruction { name: "jnz", code: 5, parameters: 2, writes: None },
+    Instruction { name: "jz", code: 6, parameters: 2, writes: None },
+    Instruction { name: "lt", code: 7, parameters: 3, writes: Some(2) },
+    Instruction { name: "eq", code: 8, parameters: 3, writes: Some(2) },
+    Instruction { name: "arb", code: 9, parameters: 1, writes: None },
+    Instruction { name: "hlt", code: 99, parameters: 0, writes: None },
+];
+
+#[derive(Copy, Clone, Debug)]
 enum Opcode {
     Add {
         mode: (Mode, Mode, Mode),
@@ -106,96 +163,253 @@ impl Opcode {
             Opcode::Halt => 0,
         }
     }
-    fn get_opcode(memory: &HashMap<usize, i128>, position: usize) -> Result<Opcode, IntcodeError> {
-        let code = memory[&position];
+
+    fn instruction(code: i128) -> Option<&'static Instruction> {
+        INSTRUCTIONS
+            .iter()
+            .find(|instruction| instruction.code == code % 100)
+    }
+
+    /// How many parameters follow an instruction, or `None` if it isn't one.
+    fn parameter_count(code: i128) -> Option<usize> {
+        Opcode::instruction(code).map(|instruction| instruction.parameters)
+    }
+
+    fn code(&self) -> i128 {
+        match self {
+            Opcode::Add { .. } => 1,
+            Opcode::Multiply { .. } => 2,
+            Opcode::Input { .. } => 3,
+            Opcode::Output { .. } => 4,
+            Opcode::JumpIfTrue { .. } => 5,
+            Opcode::JumpIfFalse { .. } => 6,
+            Opcode::LessThan { .. } => 7,
+            Opcode::Equals { .. } => 8,
+            Opcode::SetBase { .. } => 9,
+            Opcode::Halt => 99,
+        }
+    }
+
+    fn parameters(&self) -> Vec<(Mode, i128)> {
+        match *self {
+            Opcode::Add { mode, a, b, dest }
+            | Opcode::Multiply { mode, a, b, dest }
+            | Opcode::LessThan { mode, a, b, dest }
+            | Opcode::Equals { mode, a, b, dest } => {
+                vec![(mode.0, a), (mode.1, b), (mode.2, dest)]
+            }
+            Opcode::JumpIfTrue { mode, test, pos } | Opcode::JumpIfFalse { mode, test, pos } => {
+                vec![(mode.0, test), (mode.1, pos)]
+            }
+            Opcode::Input { mode, dest } => vec![(mode, dest)],
+            Opcode::Output { mode, src } => vec![(mode, src)],
+            Opcode::SetBase { mode, base } => vec![(mode, base)],
+            Opcode::Halt => vec![],
+        }
+    }
+
+    /// The instruction as it appears on the tape.
+    fn encode(&self) -> Vec<i128> {
+        let parameters = self.parameters();
+        let mut code = self.code();
+        for (i, (mode, _)) in parameters.iter().enumerate() {
+            code += mode.flag() * 10_i128.pow(i as u32 + 2);
+        }
+        std::iter::once(code)
+            .chain(parameters.iter().map(|&(_, value)| value))
+            .collect()
+    }
+    fn get_opcode(memory: &Memory, position: usize) -> Result<Opcode, IntcodeError> {
+        let code = memory[position];
+        let count = Opcode::parameter_count(code).ok_or(IntcodeError::OpcodeNotFound {
+            position,
+            opcode: code,
+        })?;
+        if position + count >= memory.len() {
+            return Err(IntcodeError::TruncatedInstruction {
+                position,
+                opcode: code,
+            });
+        }
+        let get_mode = |flag| {
+            Mode::get_mode(flag).ok_or(IntcodeError::ModeNotFound {
+                position,
+                opcode: code,
+            })
+        };
         match code % 100 {
             1 => {
                 let mode = (
-                    Mode::get_mode(code / 100)?,
-                    Mode::get_mode(code / 1000)?,
-                    Mode::get_mode(code / 10_000)?,
+                    get_mode(code / 100)?,
+                    get_mode(code / 1000)?,
+                    get_mode(code / 10_000)?,
                 );
-                let a = memory[&(position + 1)];
-                let b = memory[&(position + 2)];
-                let dest = memory[&(position + 3)];
+                let a = memory[position + 1];
+                let b = memory[position + 2];
+                let dest = memory[position + 3];
                 Ok(Opcode::Add { mode, a, b, dest })
             }
             2 => {
                 let mode = (
-                    Mode::get_mode(code / 100)?,
-                    Mode::get_mode(code / 1000)?,
-                    Mode::get_mode(code / 10_000)?,
+                    get_mode(code / 100)?,
+                    get_mode(code / 1000)?,
+                    get_mode(code / 10_000)?,
                 );
-                let a = memory[&(position + 1)];
-                let b = memory[&(position + 2)];
-                let dest = memory[&(position + 3)];
+                let a = memory[position + 1];
+                let b = memory[position + 2];
+                let dest = memory[position + 3];
                 Ok(Opcode::Multiply { mode, a, b, dest })
             }
             3 => {
-                let mode = Mode::get_mode(code / 100)?;
-                let dest = memory[&(position + 1)];
+                let mode = get_mode(code / 100)?;
+                let dest = memory[position + 1];
                 Ok(Opcode::Input { mode, dest })
             }
             4 => {
-                let mode = Mode::get_mode(code / 100)?;
-                let src = memory[&(position + 1)];
+                let mode = get_mode(code / 100)?;
+                let src = memory[position + 1];
                 Ok(Opcode::Output { mode, src })
             }
             5 => {
-                let mode = (Mode::get_mode(code / 100)?, Mode::get_mode(code / 1000)?);
-                let test = memory[&(position + 1)];
-                let pos = memory[&(position + 2)];
+                let mode = (get_mode(code / 100)?, get_mode(code / 1000)?);
+                let test = memory[position + 1];
+                let pos = memory[position + 2];
                 Ok(Opcode::JumpIfTrue { mode, test, pos })
             }
             6 => {
-                let mode = (Mode::get_mode(code / 100)?, Mode::get_mode(code / 1000)?);
-                let test = memory[&(position + 1)];
-                let pos = memory[&(position + 2)];
+                let mode = (get_mode(code / 100)?, get_mode(code / 1000)?);
+                let test = memory[position + 1];
+                let pos = memory[position + 2];
                 Ok(Opcode::JumpIfFalse { mode, test, pos })
             }
             7 => {
                 let mode = (
-                    Mode::get_mode(code / 100)?,
-                    Mode::get_mode(code / 1000)?,
-                    Mode::get_mode(code / 10_000)?,
+                    get_mode(code / 100)?,
+                    get_mode(code / 1000)?,
+                    get_mode(code / 10_000)?,
                 );
-                let a = memory[&(position + 1)];
-                let b = memory[&(position + 2)];
-                let dest = memory[&(position + 3)];
+                let a = memory[position + 1];
+                let b = memory[position + 2];
+                let dest = memory[position + 3];
                 Ok(Opcode::LessThan { mode, a, b, dest })
             }
             8 => {
                 let mode = (
-                    Mode::get_mode(code / 100)?,
-                    Mode::get_mode(code / 1000)?,
-                    Mode::get_mode(code / 10_000)?,
+                    get_mode(code / 100)?,
+                    get_mode(code / 1000)?,
+                    get_mode(code / 10_000)?,
                 );
-                let a = memory[&(position + 1)];
-                let b = memory[&(position + 2)];
-                let dest = memory[&(position + 3)];
+                let a = memory[position + 1];
+                let b = memory[position + 2];
+                let dest = memory[position + 3];
                 Ok(Opcode::Equals { mode, a, b, dest })
             }
             9 => {
-                let mode = Mode::get_mode(code / 100)?;
-                let base = memory[&(position + 1)];
+                let mode = get_mode(code / 100)?;
+                let base = memory[position + 1];
                 Ok(Opcode::SetBase { mode, base })
             }
             99 => Ok(Opcode::Halt),
             _ => Err(IntcodeError::OpcodeNotFound {
-                opcode: memory[&position],
+                position,
+                opcode: code,
             }),
         }
     }
 }
 
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = Opcode::instruction(self.code()).map_or("?", |instruction| instruction.name);
+        write!(f, "{}", name)?;
+        for (i, (mode, value)) in self.parameters().into_iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}", separator, mode.operand(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// An Intcode machine's memory. It starts out as the tape, and grows with zeros whenever
+/// a program writes past the end. Reading past the end gives zero without growing.
+#[derive(Clone, Debug)]
+pub struct Memory {
+    cells: Vec<i128>,
+    /// Decoded instructions by position, dropped whenever a write lands inside one.
+    decoded: Option<Vec<Option<Opcode>>>,
+}
+
+impl Memory {
+    pub fn new(cells: Vec<i128>) -> Self {
+        let decoded = Some(vec![None; cells.len()]);
+        Memory { cells, decoded }
+    }
+
+    /// Turn the decoded-instruction cache on (the default) or off, to rule it out when debugging.
+    #[allow(dead_code)]
+    pub fn set_caching(&mut self, caching: bool) {
+        self.decoded = caching.then(|| vec![None; self.cells.len()]);
+    }
+
+    /// The size of the tape, including anything written past its original end.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn get(&self, address: usize) -> i128 {
+        self.cells.get(address).copied().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, address: usize, value: i128) {
+        if address >= self.cells.len() {
+            self.cells.resize(address + 1, 0);
+        }
+        self.cells[address] = value;
+        if let Some(decoded) = &mut self.decoded {
+            // The longest instruction is four cells, so only the last few can include this one.
+            let start = address.saturating_sub(3);
+            for entry in decoded.iter_mut().take(address + 1).skip(start) {
+                *entry = None;
+            }
+        }
+    }
+
+    fn decode(&mut self, position: usize) -> Result<Opcode, IntcodeError> {
+        if let Some(Some(opcode)) = self
+            .decoded
+            .as_ref()
+            .and_then(|decoded| decoded.get(position))
+        {
+            return Ok(*opcode);
+        }
+        let opcode = Opcode::get_opcode(self, position)?;
+        if let Some(decoded) = &mut self.decoded {
+            if position >= decoded.len() {
+                decoded.resize(position + 1, None);
+            }
+            decoded[position] = Some(opcode);
+        }
+        Ok(opcode)
+    }
+}
+
+impl Index<usize> for Memory {
+    type Output = i128;
+
+    fn index(&self, address: usize) -> &i128 {
+        self.cells.get(address).unwrap_or(&0)
+    }
+}
+
 #[derive(Clone)]
 pub struct Intcode {
     position: usize,
     state: State,
     base: i128,
-    pub memory: HashMap<usize, i128>,
+    pub memory: Memory,
     pub inputs: VecDeque<i128>,
     pub outputs: VecDeque<i128>,
+    last_write: Option<usize>,
 }
 
 impl fmt::Debug for Intcode {
@@ -214,52 +428,124 @@ impl Intcode {
             position: 0,
             state: State::Ready,
             base: 0,
-            memory: memory.into_iter().enumerate().collect(),
+            memory: Memory::new(memory),
             inputs: VecDeque::from(inputs),
             outputs: VecDeque::new(),
+            last_write: None,
         }
     }
 
-    fn get_value(&mut self, mode: Mode, value: i128) -> i128 {
-        match mode {
-            Mode::Immediate => value,
-            Mode::Position => *self.memory.get(&(value as usize)).unwrap_or(&0),
-            Mode::Relative => *self
-                .memory
-                .get(&((value + self.base) as usize))
-                .unwrap_or(&0),
+    fn write(&mut self, dest: usize, value: i128) {
+        self.memory.set(dest, value);
+        self.last_write = Some(dest);
+    }
+
+    // The disassembler and debugger are for reverse-engineering a program while working on a
+    // puzzle, so the finished days don't necessarily use them.
+    /// Disassemble the instruction at `position`, returning its text and size.
+    /// Anything that doesn't decode, or wouldn't assemble back to the same number
+    /// (like `hlt` with stray mode digits), is shown as a `data` directive.
+    #[allow(dead_code)]
+    pub fn disassemble_at(&self, position: usize) -> (String, usize) {
+        let code = self.memory.get(position);
+        match Opcode::get_opcode(&self.memory, position)
+            .ok()
+            .filter(|opcode| opcode.encode()[0] == code)
+        {
+            Some(opcode) => (opcode.to_string(), opcode.parameters().len() + 1),
+            None => (format!("data {}", code), 1),
         }
     }
 
-    fn get_location(&mut self, mode: Mode, value: i128) -> usize {
+    /// Disassemble the tape from `start` up to `end`, one instruction per line.
+    #[allow(dead_code)]
+    pub fn disassemble(&self, start: usize, end: usize) -> Vec<(usize, String)> {
+        let mut rv = vec![];
+        let mut position = start;
+        while position < end {
+            let (text, size) = self.disassemble_at(position);
+            rv.push((position, text));
+            position += size;
+        }
+        rv
+    }
+
+    /// The instruction pointer and the instruction there, for errors to point at.
+    fn here(&self) -> (usize, i128) {
+        (self.position, self.memory.get(self.position))
+    }
+
+    fn overflow(&self) -> IntcodeError {
+        let (position, opcode) = self.here();
+        IntcodeError::Overflow { position, opcode }
+    }
+
+    fn get_value(&self, mode: Mode, value: i128) -> Result<i128, IntcodeError> {
         match mode {
-            Mode::Immediate => panic!("ERROR!!!! Immediate mode for location!!!"),
-            Mode::Position => value as usize,
-            Mode::Relative => (value + self.base) as usize,
+            Mode::Immediate => Ok(value),
+            _ => Ok(self.memory.get(self.get_location(mode, value)?)),
         }
     }
 
+    fn get_location(&self, mode: Mode, value: i128) -> Result<usize, IntcodeError> {
+        let address = match mode {
+            Mode::Immediate => {
+                let (position, opcode) = self.here();
+                return Err(IntcodeError::ImmediateWrite { position, opcode });
+            }
+            Mode::Position => value,
+            Mode::Relative => value
+                .checked_add(self.base)
+                .ok_or_else(|| self.overflow())?,
+        };
+        usize::try_from(address)
+            .ok()
+            .filter(|&address| address < MAX_ADDRESS)
+            .ok_or_else(|| {
+                let (position, opcode) = self.here();
+                IntcodeError::InvalidAddress {
+                    position,
+                    opcode,
+                    address,
+                }
+            })
+    }
+
+    fn get_target(&self, mode: Mode, value: i128) -> Result<usize, IntcodeError> {
+        let target = self.get_value(mode, value)?;
+        usize::try_from(target).map_err(|_| {
+            let (position, opcode) = self.here();
+            IntcodeError::InvalidJump {
+                position,
+                opcode,
+                target,
+            }
+        })
+    }
+
     pub fn run_step(&mut self) -> Result<State, IntcodeError> {
-        let opcode = Opcode::get_opcode(&self.memory, self.position)?;
+        let (start, code) = self.here();
+        let opcode = self.memory.decode(self.position)?;
         let mut jumped = false;
+        self.last_write = None;
         // println!("Executing {:?} b:{}", opcode, self.base);
         match &opcode {
             Opcode::Add { mode, a, b, dest } => {
-                let a = self.get_value(mode.0, *a);
-                let b = self.get_value(mode.1, *b);
-                let dest = self.get_location(mode.2, *dest);
-                self.memory.insert(dest, a + b);
+                let a = self.get_value(mode.0, *a)?;
+                let b = self.get_value(mode.1, *b)?;
+                let dest = self.get_location(mode.2, *dest)?;
+                self.write(dest, a.checked_add(b).ok_or_else(|| self.overflow())?);
             }
             Opcode::Multiply { mode, a, b, dest } => {
-                let a = self.get_value(mode.0, *a);
-                let b = self.get_value(mode.1, *b);
-                let dest = self.get_location(mode.2, *dest);
-                self.memory.insert(dest, a * b);
+                let a = self.get_value(mode.0, *a)?;
+                let b = self.get_value(mode.1, *b)?;
+                let dest = self.get_location(mode.2, *dest)?;
+                self.write(dest, a.checked_mul(b).ok_or_else(|| self.overflow())?);
             }
             Opcode::Input { mode, dest } => match self.inputs.pop_front() {
                 Some(value) => {
-                    let dest = self.get_location(*mode, *dest);
-                    self.memory.insert(dest, value);
+                    let dest = self.get_location(*mode, *dest)?;
+                    self.write(dest, value);
                     self.state = State::Ready;
                 }
                 None => {
@@ -268,35 +554,39 @@ impl Intcode {
                 }
             },
             Opcode::Output { mode, src } => {
-                let value = self.get_value(*mode, *src);
+                let value = self.get_value(*mode, *src)?;
                 self.outputs.push_front(value);
             }
             Opcode::JumpIfTrue { mode, test, pos } => {
-                if self.get_value(mode.0, *test) != 0 {
+                if self.get_value(mode.0, *test)? != 0 {
                     jumped = true;
-                    self.position = self.get_value(mode.1, *pos) as usize;
+                    self.position = self.get_target(mode.1, *pos)?;
                 }
             }
             Opcode::JumpIfFalse { mode, test, pos } => {
-                if self.get_value(mode.0, *test) == 0 {
+                if self.get_value(mode.0, *test)? == 0 {
                     jumped = true;
-                    self.position = self.get_value(mode.1, *pos) as usize;
+                    self.position = self.get_target(mode.1, *pos)?;
                 }
             }
             Opcode::LessThan { mode, a, b, dest } => {
-                let a = self.get_value(mode.0, *a);
-                let b = self.get_value(mode.1, *b);
-                let dest = self.get_location(mode.2, *dest);
-                self.memory.insert(dest, if a < b { 1 } else { 0 });
+                let a = self.get_value(mode.0, *a)?;
+                let b = self.get_value(mode.1, *b)?;
+                let dest = self.get_location(mode.2, *dest)?;
+                self.write(dest, if a < b { 1 } else { 0 });
             }
             Opcode::Equals { mode, a, b, dest } => {
-                let a = self.get_value(mode.0, *a);
-                let b = self.get_value(mode.1, *b);
-                let dest = self.get_location(mode.2, *dest);
-                self.memory.insert(dest, if a == b { 1 } else { 0 });
+                let a = self.get_value(mode.0, *a)?;
+                let b = self.get_value(mode.1, *b)?;
+                let dest = self.get_location(mode.2, *dest)?;
+                self.write(dest, if a == b { 1 } else { 0 });
             }
             Opcode::SetBase { mode, base } => {
-                self.base += self.get_value(*mode, *base);
+                let offset = self.get_value(*mode, *base)?;
+                self.base = self
+                    .base
+                    .checked_add(offset)
+                    .ok_or_else(|| self.overflow())?;
             }
             Opcode::Halt => {
                 self.state = State::Halted;
@@ -307,7 +597,9 @@ impl Intcode {
         }
         if self.position >= self.memory.len() {
             Err(IntcodeError::InvalidPosition {
-                position: self.position,
+                position: start,
+                opcode: code,
+                target: self.position,
                 len: self.memory.len(),
             })
         } else {
@@ -349,4 +641,945 @@ impl Intcode {
         }
         Ok(self.state)
     }
+
+    /// Queue `line` as ASCII input, followed by a newline.
+    pub fn send_line(&mut self, line: &str) {
+        self.inputs
+            .extend(line.chars().chain(['\n']).map(|c| c as i128));
+    }
+
+    /// Take all the pending output, oldest first, as text. Anything that isn't ASCII
+    /// is written out as a number.
+    pub fn read_string(&mut self) -> String {
+        let (text, _) = self.read_output(true);
+        text
+    }
+
+    /// Take all the pending output, oldest first, splitting the ASCII text
+    /// from the numbers that don't fit in it.
+    pub fn read_ascii(&mut self) -> (String, Vec<i128>) {
+        self.read_output(false)
+    }
+
+    fn read_output(&mut self, inline_numbers: bool) -> (String, Vec<i128>) {
+        let mut text = String::new();
+        let mut numbers = vec![];
+        while let Some(output) = self.outputs.pop_back() {
+            match u8::try_from(output) {
+                Ok(byte) if byte.is_ascii() => text.push(byte as char),
+                _ if inline_numbers => text.push_str(&output.to_string()),
+                _ => numbers.push(output),
+            }
+        }
+        (text, numbers)
+    }
+
+    /// The machine's state as JSON, with the tape and queues in the usual comma-separated format.
+    #[allow(dead_code)]
+    pub fn save(&self) -> Value {
+        json!({
+            "position": self.position,
+            "base": self.base.to_string(),
+            "state": format!("{:?}", self.state),
+            "memory": self.memory.cells.iter().join(","),
+            "inputs": self.inputs.iter().join(","),
+            "outputs": self.outputs.iter().join(","),
+        })
+    }
+
+    /// Rebuild a machine from `save`'s output, or `None` if it's not a saved machine.
+    #[allow(dead_code)]
+    pub fn restore(saved: &Value) -> Option<Self> {
+        let split = |key: &str| -> Option<Vec<i128>> {
+            let values = saved[key].as_str()?;
+            if values.is_empty() {
+                return Some(vec![]);
+            }
+            values.split(',').map(|value| value.parse().ok()).collect()
+        };
+        let state = match saved["state"].as_str()? {
+            "Ready" => State::Ready,
+            "WaitingForInput" => State::WaitingForInput,
+            "Halted" => State::Halted,
+            _ => return None,
+        };
+        Some(Intcode {
+            position: saved["position"].as_u64()? as usize,
+            state,
+            base: saved["base"].as_str()?.parse().ok()?,
+            memory: Memory::new(split("memory")?),
+            inputs: split("inputs")?.into(),
+            outputs: split("outputs")?.into(),
+            last_write: None,
+        })
+    }
+}
+
+/// Disassemble a whole tape into source that `assemble` turns back into the same tape.
+#[allow(dead_code)]
+pub fn disassemble_tape(tape: &[i128]) -> String {
+    let machine = Intcode::new(tape.to_vec(), vec![]);
+    machine
+        .disassemble(0, tape.len())
+        .into_iter()
+        .map(|(_, text)| text + "\n")
+        .collect()
+}
+
+/// A number in assembly source, which can be a label's address plus an offset.
+enum Word<'a> {
+    Number(i128),
+    Label(&'a str, i128),
+}
+
+fn is_label(s: &str) -> bool {
+    s != "rb"
+        && s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_word(s: &str) -> Option<Word<'_>> {
+    let s = s.trim();
+    if let Ok(number) = s.parse() {
+        return Some(Word::Number(number));
+    }
+    let (label, offset) = match s.find(['+', '-']) {
+        Some(i) => (s[..i].trim(), s[i..].replace(' ', "").parse().ok()?),
+        None => (s, 0),
+    };
+    is_label(label).then_some(Word::Label(label, offset))
+}
+
+/// Parse a parameter written the way the disassembler shows it: `5`, `[5]`, or `[rb+5]`,
+/// where any of the numbers can also be a label.
+fn parse_parameter(s: &str) -> Option<(Mode, Word<'_>)> {
+    let s = s.trim();
+    let Some(inner) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) else {
+        return Some((Mode::Immediate, parse_word(s)?));
+    };
+    let inner = inner.trim();
+    match inner.strip_prefix("rb").map(str::trim_start) {
+        Some("") => Some((Mode::Relative, Word::Number(0))),
+        Some(offset) if offset.starts_with('+') => {
+            Some((Mode::Relative, parse_word(&offset[1..])?))
+        }
+        Some(offset) if offset.starts_with('-') => Some((
+            Mode::Relative,
+            Word::Number(offset.replace(' ', "").parse().ok()?),
+        )),
+        _ => Some((Mode::Position, parse_word(inner)?)),
+    }
+}
+
+/// Split on commas that aren't inside a string, dropping any `;` comment.
+fn split_source(line: &str) -> (Vec<&str>, bool) {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ',' if !in_string => {
+                parts.push(&line[start..i]);
+                start = i + 1;
+            }
+            ';' if !in_string => {
+                parts.push(&line[start..i]);
+                return (parts, in_string);
+            }
+            _ => {}
+        }
+    }
+    parts.push(&line[start..]);
+    (parts, in_string)
+}
+
+fn parse_string(s: &str) -> Option<Vec<i128>> {
+    let mut rv = vec![];
+    let mut chars = s.strip_prefix('"')?.strip_suffix('"')?.chars();
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                c @ ('\\' | '"') => c,
+                _ => return None,
+            },
+            c => c,
+        };
+        rv.push(c as i128);
+    }
+    Some(rv)
+}
+
+/// Assemble an Intcode program, one instruction per line, in the syntax the disassembler uses.
+///
+/// ```text
+/// ; Echo numbers until it reads a zero.
+/// loop:  in [value]
+///        jz [value], end
+///        out [value]
+///        jz 0, loop
+/// end:   hlt
+/// value: data 0
+/// ```
+///
+/// Parameters are `5` (immediate), `[5]` (position), or `[rb+5]` (relative), and any
+/// number can be a label, optionally with an offset like `value+1`. `data` lays down numbers,
+/// labels, or strings like `"hello\n"` as they are, and `;` starts a comment.
+#[allow(dead_code)]
+pub fn assemble(source: &str) -> Result<Vec<i128>, IntcodeError> {
+    let mut tape = vec![];
+    let mut labels = HashMap::new();
+    let mut fixups = vec![];
+
+    for (number, line) in source.lines().enumerate() {
+        let error = |message: &str| IntcodeError::AssemblyError {
+            line: number + 1,
+            message: message.to_owned(),
+        };
+        let (parts, unterminated) = split_source(line);
+        if unterminated {
+            return Err(error("unterminated string"));
+        }
+        let mut first = parts[0].trim();
+        if let Some((label, rest)) = first.split_once(':')
+            && is_label(label.trim())
+        {
+            if labels.insert(label.trim(), tape.len()).is_some() {
+                return Err(error(&format!("{} is defined twice", label.trim())));
+            }
+            first = rest.trim();
+        }
+        if first.is_empty() {
+            if parts.len() > 1 {
+                return Err(error("parameters without an instruction"));
+            }
+            continue;
+        }
+        let (name, parameter) = first.split_once(char::is_whitespace).unwrap_or((first, ""));
+        let parameters: Vec<&str> = std::iter::once(parameter)
+            .chain(parts[1..].iter().copied())
+            .map(str::trim)
+            .collect();
+        let parameters = if parameters == [""] {
+            vec![]
+        } else {
+            parameters
+        };
+
+        let mut words = vec![];
+        if name == "data" {
+            for parameter in parameters {
+                if parameter.starts_with('"') {
+                    let string = parse_string(parameter).ok_or_else(|| error("bad string"))?;
+                    tape.extend(string);
+                } else {
+                    words.push((tape.len(), parse_word(parameter)));
+                    tape.push(0);
+                }
+            }
+        } else {
+            let instruction = INSTRUCTIONS
+                .iter()
+                .find(|instruction| instruction.name == name)
+                .ok_or_else(|| error(&format!("unknown instruction {}", name)))?;
+            if parameters.len() != instruction.parameters {
+                return Err(error(&format!(
+                    "{} takes {} parameters",
+                    name, instruction.parameters
+                )));
+            }
+            let mut code = instruction.code;
+            let start = tape.len();
+            tape.push(0);
+            for (i, parameter) in parameters.into_iter().enumerate() {
+                let (mode, word) = parse_parameter(parameter)
+                    .ok_or_else(|| error(&format!("bad parameter {}", parameter)))?;
+                if mode == Mode::Immediate && instruction.writes == Some(i) {
+                    return Err(error(&format!("{} can't write to an immediate", name)));
+                }
+                code += mode.flag() * 10_i128.pow(i as u32 + 2);
+                words.push((tape.len(), Some(word)));
+                tape.push(0);
+            }
+            tape[start] = code;
+        }
+
+        for (position, word) in words {
+            match word {
+                Some(Word::Number(value)) => tape[position] = value,
+                Some(Word::Label(label, offset)) => {
+                    fixups.push((position, label, offset, number + 1))
+                }
+                None => return Err(error("bad number")),
+            }
+        }
+    }
+
+    for (position, label, offset, line) in fixups {
+        let address = labels
+            .get(label)
+            .ok_or_else(|| IntcodeError::AssemblyError {
+                line,
+                message: format!("unknown label {}", label),
+            })?;
+        tape[position] = *address as i128 + offset;
+    }
+    Ok(tape)
+}
+
+/// Why `Debugger::run` stopped.
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The next instruction to run is at a breakpoint.
+    Breakpoint(usize),
+    /// The instruction at `position` wrote `value` to a break-on-write `address`.
+    Write {
+        position: usize,
+        address: usize,
+        value: i128,
+    },
+    /// The machine halted or is waiting for input.
+    State(State),
+}
+
+/// A change to a watched address.
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Watch {
+    pub position: usize,
+    pub address: usize,
+    pub old: i128,
+    pub new: i128,
+}
+
+impl fmt::Display for Watch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>6}: [{}] {} -> {}",
+            self.position, self.address, self.old, self.new
+        )
+    }
+}
+
+/// One executed instruction.
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    pub position: usize,
+    pub base: i128,
+    pub instruction: String,
+    pub write: Option<(usize, i128)>,
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>6}: {:<32} rb={}",
+            self.position, self.instruction, self.base
+        )?;
+        if let Some((address, value)) = self.write {
+            write!(f, " [{}]={}", address, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps an `Intcode` machine with breakpoints, watchpoints, and an execution trace,
+/// for picking apart what a program is doing.
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub struct Debugger {
+    pub machine: Intcode,
+    breakpoints: BTreeSet<usize>,
+    write_breakpoints: BTreeSet<usize>,
+    watchpoints: BTreeSet<usize>,
+    tracing: bool,
+    stopped_at: Option<usize>,
+    pub trace: Vec<Trace>,
+    pub watches: Vec<Watch>,
+}
+
+#[allow(dead_code)]
+impl Debugger {
+    pub fn new(machine: Intcode) -> Self {
+        Debugger {
+            machine,
+            breakpoints: BTreeSet::new(),
+            write_breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            tracing: false,
+            stopped_at: None,
+            trace: vec![],
+            watches: vec![],
+        }
+    }
+
+    /// Stop before running the instruction at `position`.
+    pub fn break_at(&mut self, position: usize) {
+        self.breakpoints.insert(position);
+    }
+
+    /// Stop after any instruction that writes to `address`.
+    pub fn break_on_write(&mut self, address: usize) {
+        self.write_breakpoints.insert(address);
+    }
+
+    /// Log every change to `address` in `watches`, without stopping.
+    pub fn watch(&mut self, address: usize) {
+        self.watchpoints.insert(address);
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+        self.write_breakpoints.clear();
+        self.watchpoints.clear();
+    }
+
+    /// Record every executed instruction in `trace`.
+    pub fn set_tracing(&mut self, tracing: bool) {
+        self.tracing = tracing;
+    }
+
+    /// Run a single instruction, ignoring breakpoints.
+    pub fn step(&mut self) -> Result<State, IntcodeError> {
+        let position = self.machine.position;
+        let base = self.machine.base;
+        let watched: Vec<(usize, i128)> = self
+            .watchpoints
+            .iter()
+            .map(|&address| (address, self.read(address)))
+            .collect();
+        let instruction = self
+            .tracing
+            .then(|| self.machine.disassemble_at(position).0);
+
+        let state = self.machine.run_step()?;
+
+        let write = self
+            .machine
+            .last_write
+            .map(|address| (address, self.read(address)));
+        if let Some(instruction) = instruction {
+            self.trace.push(Trace {
+                position,
+                base,
+                instruction,
+                write,
+            });
+        }
+        for (address, old) in watched {
+            let new = self.read(address);
+            if new != old {
+                self.watches.push(Watch {
+                    position,
+                    address,
+                    old,
+                    new,
+                });
+            }
+        }
+        Ok(state)
+    }
+
+    /// Run until a breakpoint is hit, or the machine halts or needs input.
+    /// Calling `run` again after stopping at a breakpoint continues past it.
+    pub fn run(&mut self) -> Result<Stop, IntcodeError> {
+        if self.machine.state == State::Halted {
+            return Err(IntcodeError::MachineHalted);
+        }
+        let mut resume = self.stopped_at.take();
+        loop {
+            let position = self.machine.position;
+            if resume != Some(position) && self.breakpoints.contains(&position) {
+                self.stopped_at = Some(position);
+                return Ok(Stop::Breakpoint(position));
+            }
+            resume = None;
+            let state = self.step()?;
+            if let Some(address) = self.machine.last_write
+                && self.write_breakpoints.contains(&address)
+            {
+                return Ok(Stop::Write {
+                    position,
+                    address,
+                    value: self.read(address),
+                });
+            }
+            if state != State::Ready {
+                return Ok(Stop::State(state));
+            }
+        }
+    }
+
+    fn read(&self, address: usize) -> i128 {
+        self.machine.memory.get(address)
+    }
+}
+
+/// Where a machine in a `Network` sends its output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Route {
+    /// Leave the output with the machine.
+    Keep,
+    /// Pass every value straight on to the named machine.
+    Pipe(String),
+    /// Split the output into packets of this many values. The first value of each packet is
+    /// the address to send the rest to: a machine's address is the order it was added in, and
+    /// packets for any other address wait in that address's mailbox.
+    Packets(usize),
+}
+
+/// Which machine a `Network` runs next.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    /// Every machine takes a turn each round, whether or not anything was sent to it.
+    RoundRobin,
+    /// Only machines with new input run, in the order the input arrived.
+    Events,
+}
+
+/// What a `Network` is doing after a step.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Activity {
+    Busy,
+    /// Every machine is waiting for input that nothing is going to send.
+    Idle,
+    Halted,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    name: String,
+    machine: Intcode,
+    route: Route,
+    idle_input: Option<i128>,
+    last_output: Option<i128>,
+}
+
+/// A set of named machines wired together by routes, for the puzzles
+/// where several copies of a program talk to each other.
+#[derive(Clone, Debug)]
+pub struct Network {
+    nodes: Vec<Node>,
+    schedule: Schedule,
+    pending: VecDeque<usize>,
+    mailboxes: BTreeMap<i128, Vec<Vec<i128>>>,
+    idle_after: usize,
+    quiet: usize,
+}
+
+impl Network {
+    pub fn new(schedule: Schedule) -> Self {
+        Network {
+            nodes: vec![],
+            schedule,
+            pending: VecDeque::new(),
+            mailboxes: BTreeMap::new(),
+            idle_after: 1,
+            quiet: 0,
+        }
+    }
+
+    /// Add a machine, returning its address.
+    pub fn add(&mut self, name: &str, machine: Intcode, route: Route) -> usize {
+        let address = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_owned(),
+            machine,
+            route,
+            idle_input: None,
+            last_output: None,
+        });
+        self.pending.push_back(address);
+        address
+    }
+
+    /// Give the named machine `value` whenever it takes a turn with nothing to read.
+    pub fn set_idle_input(&mut self, name: &str, value: i128) -> Result<(), IntcodeError> {
+        let address = self.address(name)?;
+        self.nodes[address].idle_input = Some(value);
+        Ok(())
+    }
+
+    /// How many quiet rounds in a row it takes for a round robin network to count as idle,
+    /// for programs that keep polling for a while before giving up.
+    pub fn set_idle_after(&mut self, rounds: usize) {
+        self.idle_after = rounds;
+    }
+
+    fn address(&self, name: &str) -> Result<usize, IntcodeError> {
+        self.nodes
+            .iter()
+            .position(|node| node.name == name)
+            .ok_or_else(|| IntcodeError::UnknownMachine {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Queue `values` as input for the named machine.
+    pub fn send(&mut self, name: &str, values: &[i128]) -> Result<(), IntcodeError> {
+        let address = self.address(name)?;
+        self.deliver(address as i128, values);
+        self.quiet = 0;
+        Ok(())
+    }
+
+    /// The last value the named machine sent, wherever it went.
+    pub fn last_output(&self, name: &str) -> Result<Option<i128>, IntcodeError> {
+        Ok(self.nodes[self.address(name)?].last_output)
+    }
+
+    /// Take the packets sent to an address that doesn't have a machine, oldest first.
+    pub fn take_mail(&mut self, address: i128) -> Vec<Vec<i128>> {
+        self.mailboxes.remove(&address).unwrap_or_default()
+    }
+
+    fn deliver(&mut self, address: i128, values: &[i128]) {
+        match usize::try_from(address)
+            .ok()
+            .filter(|&address| address < self.nodes.len())
+        {
+            Some(address) => {
+                self.nodes[address].machine.inputs.extend(values);
+                if self.schedule == Schedule::Events && !self.pending.contains(&address) {
+                    self.pending.push_back(address);
+                }
+            }
+            None => self
+                .mailboxes
+                .entry(address)
+                .or_default()
+                .push(values.to_vec()),
+        }
+    }
+
+    /// Run one machine until it blocks, and route what it sent.
+    /// Returns whether it sent anything.
+    fn run_node(&mut self, address: usize) -> Result<bool, IntcodeError> {
+        let pipe = match &self.nodes[address].route {
+            Route::Pipe(name) => Some(self.address(name)? as i128),
+            _ => None,
+        };
+        let node = &mut self.nodes[address];
+        if node.machine.state == State::Halted {
+            return Ok(false);
+        }
+        if node.machine.inputs.is_empty()
+            && let Some(value) = node.idle_input
+        {
+            node.machine.inputs.push_back(value);
+        }
+        let before = node.machine.outputs.len();
+        node.machine.run_tape()?;
+        let sent = node.machine.outputs.len() > before;
+        if sent {
+            node.last_output = node.machine.outputs.front().copied();
+        }
+
+        let mut messages = vec![];
+        match (&node.route, pipe) {
+            (Route::Pipe(_), Some(destination)) => {
+                let values: Vec<i128> = node.machine.outputs.drain(..).rev().collect();
+                messages.push((destination, values));
+            }
+            (&Route::Packets(size), _) => {
+                let outputs = &mut node.machine.outputs;
+                while outputs.len() >= size {
+                    let packet: Vec<i128> = outputs.drain(outputs.len() - size..).rev().collect();
+                    messages.push((packet[0], packet[1..].to_vec()));
+                }
+            }
+            _ => {}
+        }
+        for (address, values) in messages {
+            self.deliver(address, &values);
+        }
+        Ok(sent)
+    }
+
+    /// Run a round of the schedule: every machine once for round robin,
+    /// or the next machine with input for events.
+    pub fn step(&mut self) -> Result<Activity, IntcodeError> {
+        match self.schedule {
+            Schedule::RoundRobin => {
+                let mut sent = false;
+                for address in 0..self.nodes.len() {
+                    sent |= self.run_node(address)?;
+                }
+                let waiting = self.nodes.iter().all(|node| {
+                    node.machine.state == State::Halted
+                        || (node.machine.state == State::WaitingForInput
+                            && node.machine.inputs.is_empty())
+                });
+                self.quiet = if !sent && waiting { self.quiet + 1 } else { 0 };
+            }
+            Schedule::Events => {
+                if let Some(address) = self.pending.pop_front() {
+                    self.run_node(address)?;
+                }
+            }
+        }
+
+        if self
+            .nodes
+            .iter()
+            .all(|node| node.machine.state == State::Halted)
+        {
+            return Ok(Activity::Halted);
+        }
+        let idle = match self.schedule {
+            Schedule::RoundRobin => self.quiet >= self.idle_after,
+            Schedule::Events => self.pending.is_empty(),
+        };
+        Ok(if idle { Activity::Idle } else { Activity::Busy })
+    }
+
+    /// Run until every machine has halted or the network goes idle.
+    pub fn run(&mut self) -> Result<Activity, IntcodeError> {
+        loop {
+            match self.step()? {
+                Activity::Busy => {}
+                activity => return Ok(activity),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_memory() {
+    use pretty_assertions::assert_eq;
+
+    let mut memory = Memory::new(vec![1101, 1, 2, 3, 99]);
+    assert_eq!(memory.decode(0).unwrap().to_string(), "add 1, 2, [3]");
+    memory.set(2, 5);
+    assert_eq!(memory.decode(0).unwrap().to_string(), "add 1, 5, [3]");
+    memory.set(10, 7);
+    assert_eq!(
+        (memory.len(), memory[9], memory[10], memory[20]),
+        (11, 0, 7, 0)
+    );
+
+    let mut machine = Intcode::new(vec![1101, 5, 6, 1000, 99], vec![]);
+    assert_eq!(machine.run_tape(), Ok(State::Halted));
+    assert_eq!(machine.memory[1000], 11);
+
+    let mut machine = Intcode::new(vec![22101, 5, -1, 0, 99], vec![]);
+    assert_eq!(
+        machine.run_tape(),
+        Err(IntcodeError::InvalidAddress {
+            position: 0,
+            opcode: 22101,
+            address: -1
+        })
+    );
+}
+
+#[test]
+fn test_errors() {
+    use pretty_assertions::assert_eq;
+
+    let run = |tape: Vec<i128>| Intcode::new(tape, vec![]).run_tape();
+    assert_eq!(
+        run(vec![1101, 1, 1, 5, 42, 0]),
+        Err(IntcodeError::OpcodeNotFound {
+            position: 4,
+            opcode: 42
+        })
+    );
+    assert_eq!(
+        run(vec![301, 0, 0, 0, 99]),
+        Err(IntcodeError::ModeNotFound {
+            position: 0,
+            opcode: 301
+        })
+    );
+    assert_eq!(
+        run(vec![1, 0, 0]),
+        Err(IntcodeError::TruncatedInstruction {
+            position: 0,
+            opcode: 1
+        })
+    );
+    assert_eq!(
+        run(vec![11101, 1, 2, 3, 99]),
+        Err(IntcodeError::ImmediateWrite {
+            position: 0,
+            opcode: 11101
+        })
+    );
+    assert_eq!(
+        run(vec![1105, 1, -3, 99]),
+        Err(IntcodeError::InvalidJump {
+            position: 0,
+            opcode: 1105,
+            target: -3
+        })
+    );
+    assert_eq!(
+        run(vec![1101, 1, 1, 1 << 40, 99]),
+        Err(IntcodeError::InvalidAddress {
+            position: 0,
+            opcode: 1101,
+            address: 1 << 40
+        })
+    );
+    assert_eq!(
+        run(vec![1102, i128::MAX, 2, 0, 99]),
+        Err(IntcodeError::Overflow {
+            position: 0,
+            opcode: 1102
+        })
+    );
+    assert_eq!(
+        run(vec![1101, 0, 0, 0]),
+        Err(IntcodeError::InvalidPosition {
+            position: 0,
+            opcode: 1101,
+            target: 4,
+            len: 4
+        })
+    );
+}
+
+#[test]
+fn test_debugger() {
+    use pretty_assertions::assert_eq;
+
+    let machine = Intcode::new(vec![1, 9, 10, 9, 1006, 9, 0, 99, 0, 1, 2], vec![]);
+    assert_eq!(
+        machine.disassemble(0, 11),
+        vec![
+            (0, "add [9], [10], [9]".to_string()),
+            (4, "jz [9], 0".to_string()),
+            (7, "hlt".to_string()),
+            (8, "data 0".to_string()),
+            (9, "data 1".to_string()),
+            (10, "data 2".to_string()),
+        ]
+    );
+
+    let mut debugger = Debugger::new(machine);
+    debugger.set_tracing(true);
+    debugger.watch(9);
+    debugger.break_on_write(9);
+    debugger.break_at(4);
+    assert_eq!(
+        debugger.run(),
+        Ok(Stop::Write {
+            position: 0,
+            address: 9,
+            value: 3
+        })
+    );
+    assert_eq!(debugger.run(), Ok(Stop::Breakpoint(4)));
+    assert_eq!(debugger.run(), Ok(Stop::State(State::Halted)));
+    assert_eq!(debugger.trace.len(), 3);
+    assert_eq!(
+        debugger.watches,
+        vec![Watch {
+            position: 0,
+            address: 9,
+            old: 1,
+            new: 3
+        }]
+    );
+}
+
+#[test]
+fn test_network() {
+    use pretty_assertions::assert_eq;
+
+    let echo = assemble(
+        "
+        ; Read until there's something other than -1, and send it to address 9.
+        loop:  in [value]
+               eq [value], -1, [idle]
+               jnz [idle], loop
+               out 9
+               out [value]
+               jnz 1, loop
+        value: data 0
+        idle:  data 0
+        ",
+    )
+    .unwrap();
+    let mut network = Network::new(Schedule::RoundRobin);
+    network.add("echo", Intcode::new(echo, vec![]), Route::Packets(2));
+    network.set_idle_input("echo", -1).unwrap();
+    network.send("echo", &[42]).unwrap();
+    assert_eq!(network.run(), Ok(Activity::Idle));
+    assert_eq!(network.take_mail(9), vec![vec![42]]);
+    assert_eq!(network.last_output("echo"), Ok(Some(42)));
+    assert_eq!(
+        network.send("nobody", &[1]),
+        Err(IntcodeError::UnknownMachine {
+            name: "nobody".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_assembler() {
+    use pretty_assertions::assert_eq;
+
+    let source = "
+        ; Echo numbers until it reads a zero.
+        loop:  in [value]
+               jz [value], end
+               out [value]
+               jz 0, loop
+        end:   hlt
+        value: data 0
+    ";
+    let tape = assemble(source).unwrap();
+    assert_eq!(tape, vec![3, 11, 1006, 11, 10, 4, 11, 1106, 0, 0, 99, 0]);
+    assert_eq!(
+        disassemble_tape(&tape),
+        "in [11]\njz [11], 10\nout [11]\njz 0, 0\nhlt\ndata 0\n"
+    );
+    let mut machine = Intcode::new(tape, vec![4, 5, 0]);
+    assert_eq!(machine.run_tape(), Ok(State::Halted));
+    assert_eq!(machine.outputs, vec![5, 4]);
+
+    let tape = assemble(
+        r#"
+                 arb message  ; rb points at the next character.
+        print:   out [rb+0]
+                 arb 1
+                 jnz [rb], print
+                 hlt
+        message: data "hi\n", 0
+        "#,
+    )
+    .unwrap();
+    let mut machine = Intcode::new(tape.clone(), vec![]);
+    assert_eq!(machine.run_tape(), Ok(State::Halted));
+    assert_eq!(machine.read_string(), "hi\n");
+
+    for tape in [tape, vec![10099, 1, -5, 21101, 3, 4, -1]] {
+        assert_eq!(assemble(&disassemble_tape(&tape)), Ok(tape));
+    }
+
+    assert_eq!(
+        assemble("in [x]\nadd 1, 2, 3"),
+        Err(IntcodeError::AssemblyError {
+            line: 2,
+            message: "add can't write to an immediate".to_string()
+        })
+    );
+    assert_eq!(
+        assemble("in [x]"),
+        Err(IntcodeError::AssemblyError {
+            line: 1,
+            message: "unknown label x".to_string()
+        })
+    );
 }
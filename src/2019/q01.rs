@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q01.data");
+input!("q01");
 
 fn process_data_a(data: &str) -> u32 {
     let mut sum = 0;
@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use crate::intcode::Intcode;
+use super::intcode::Intcode;
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 
 fn process_data_a(data: &str) -> i128 {
     let mut ints: Vec<i128> = data
@@ -14,7 +14,7 @@ fn process_data_a(data: &str) -> i128 {
     ints[2] = 2;
     let mut machine = Intcode::new(ints, vec![]);
     match machine.run_tape() {
-        Ok(_) => machine.memory[&0],
+        Ok(_) => machine.memory[0],
         Err(code) => {
             println!("ERROR!!! in code {}", code);
             0
@@ -33,7 +33,7 @@ fn process_data_b(data: &str) -> i128 {
             ints[1] = noun;
             ints[2] = verb;
             let mut machine = Intcode::new(ints, vec![]);
-            if machine.run_tape().is_ok() && machine.memory[&0] == 19_690_720 {
+            if machine.run_tape().is_ok() && machine.memory[0] == 19_690_720 {
                 return 100 * noun + verb;
             }
         }
@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 fn process_data_a(data: &str) -> i32 {
     let mut lines = data.lines();
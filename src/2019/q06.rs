@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 fn process_data_a(data: &str) -> i32 {
     let mut orbits: HashMap<&str, Vec<&str>> = HashMap::new();
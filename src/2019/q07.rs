@@ -1,102 +1,39 @@
 //-----------------------------------------------------
 // Setup.
 
-use crate::intcode::{Intcode, IntcodeError, State};
+use super::intcode::{Intcode, IntcodeError, Network, Route, Schedule};
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
+
+const AMPLIFIERS: [&str; 5] = ["A", "B", "C", "D", "E"];
+
+/// Chain the amplifiers together, optionally feeding E's output back into A,
+/// and return the last signal E sends.
+fn run_chain(ints: &[i128], phases: &[i128], feedback: bool) -> Result<i128, IntcodeError> {
+    let mut network = Network::new(Schedule::Events);
+    for (i, (name, phase)) in AMPLIFIERS.iter().zip(phases).enumerate() {
+        let route = match AMPLIFIERS.get(i + 1) {
+            Some(next) => Route::Pipe(next.to_string()),
+            None if feedback => Route::Pipe(AMPLIFIERS[0].to_string()),
+            None => Route::Keep,
+        };
+        network.add(name, Intcode::new(ints.to_owned(), vec![*phase]), route);
+    }
+    network.send(AMPLIFIERS[0], &[0])?;
+    network.run()?;
+    network
+        .last_output(AMPLIFIERS[4])?
+        .ok_or(IntcodeError::MissingValue)
+}
 
 fn run_amps(ints: &[i128], permutation: Vec<i128>) -> Result<i128, IntcodeError> {
-    let mut first = Intcode::new(ints.to_owned(), vec![permutation[0], 0]);
-    first.run_tape()?;
-    let mut second = Intcode::new(
-        ints.to_owned(),
-        vec![permutation[1], first.outputs.pop_back().unwrap()],
-    );
-    second.run_tape()?;
-    let mut third = Intcode::new(
-        ints.to_owned(),
-        vec![permutation[2], second.outputs.pop_back().unwrap()],
-    );
-    third.run_tape()?;
-    let mut fourth = Intcode::new(
-        ints.to_owned(),
-        vec![permutation[3], third.outputs.pop_back().unwrap()],
-    );
-    fourth.run_tape()?;
-    let mut fifth = Intcode::new(
-        ints.to_owned(),
-        vec![permutation[4], fourth.outputs.pop_back().unwrap()],
-    );
-    fifth.run_tape()?;
-    fifth.outputs.pop_back().ok_or(IntcodeError::MissingValue)
+    run_chain(ints, &permutation, false)
 }
 
 fn run_multi_amps(ints: &[i128], permutation: Vec<i128>) -> Result<i128, IntcodeError> {
-    let mut first = Intcode::new(ints.to_owned(), vec![permutation[0]]);
-    let mut state = first.run_tape()?;
-    if state != State::WaitingForInput {
-        return Err(IntcodeError::MachineNotWaiting);
-    }
-    let mut second = Intcode::new(ints.to_owned(), vec![permutation[1]]);
-    state = second.run_tape()?;
-    if state != State::WaitingForInput {
-        return Err(IntcodeError::MachineNotWaiting);
-    }
-    let mut third = Intcode::new(ints.to_owned(), vec![permutation[2]]);
-    state = third.run_tape()?;
-    if state != State::WaitingForInput {
-        return Err(IntcodeError::MachineNotWaiting);
-    }
-    let mut fourth = Intcode::new(ints.to_owned(), vec![permutation[3]]);
-    state = fourth.run_tape()?;
-    if state != State::WaitingForInput {
-        return Err(IntcodeError::MachineNotWaiting);
-    }
-    let mut fifth = Intcode::new(ints.to_owned(), vec![permutation[4]]);
-    state = fifth.run_tape()?;
-    if state != State::WaitingForInput {
-        return Err(IntcodeError::MachineNotWaiting);
-    }
-
-    let mut max = 0;
-    let mut input = 0;
-    loop {
-        first.inputs.push_back(input);
-        first.run_tape()?;
-        input = first.outputs.pop_back().ok_or(IntcodeError::MissingValue)?;
-
-        second.inputs.push_back(input);
-        second.run_tape()?;
-        input = second
-            .outputs
-            .pop_back()
-            .ok_or(IntcodeError::MissingValue)?;
-
-        third.inputs.push_back(input);
-        third.run_tape()?;
-        input = third.outputs.pop_back().ok_or(IntcodeError::MissingValue)?;
-
-        fourth.inputs.push_back(input);
-        fourth.run_tape()?;
-        input = fourth
-            .outputs
-            .pop_back()
-            .ok_or(IntcodeError::MissingValue)?;
-
-        fifth.inputs.push_back(input);
-        state = fifth.run_tape()?;
-        input = fifth.outputs.pop_back().ok_or(IntcodeError::MissingValue)?;
-
-        if input > max {
-            max = input;
-        }
-        if state == State::Halted {
-            break;
-        }
-    }
-    Ok(max)
+    run_chain(ints, &permutation, true)
 }
 
 fn process_data_a(data: &str) -> i128 {
@@ -1,11 +1,11 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::letters::recognize_letters;
+use aoc::letters::read_letters;
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 fn process_data_a(data: &str) -> i32 {
     let mut found = (999, 0, 0);
@@ -47,7 +47,7 @@ fn process_data_b(data: &str) -> String {
             letters.push(*character == '1');
         }
     }
-    recognize_letters(&letters)
+    read_letters(&letters, 25)
 }
 
 //-----------------------------------------------------
@@ -9,7 +9,7 @@ use std::{
 
 use num_rational::Rational32;
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 enum Angle {
@@ -3,10 +3,10 @@
 
 use std::collections::HashMap;
 
-use crate::intcode::{Intcode, State};
-use aoc::letters::recognize_letters;
+use super::intcode::{Intcode, State};
+use aoc::letters::read_letters;
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 #[derive(Debug)]
 enum Heading {
@@ -123,7 +123,8 @@ fn process_data_b(data: &str) -> String {
             letters.push(panels.get(&(-x, y)) == Some(&1));
         }
     }
-    recognize_letters(&letters)
+    let width = (max_y - min_y + 1) as usize;
+    read_letters(&letters, width)
 }
 
 //-----------------------------------------------------
@@ -5,7 +5,7 @@ use itertools::Itertools;
 use nom::{IResult, Parser, bytes::complete::tag, character::complete::i32, multi::many1};
 use num_integer::lcm;
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 struct Moon {
@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use crate::intcode::{Intcode, State};
+use super::intcode::{Intcode, State};
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 fn process_data_a(data: &str) -> usize {
     let ints: Vec<i128> = data
@@ -10,7 +10,7 @@ use nom::{
     multi::{many1, separated_list1},
 };
 
-static INPUT: &str = include_str!("data/q14.data");
+input!("q14");
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 struct Element<'a> {
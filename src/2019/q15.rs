@@ -3,9 +3,9 @@
 
 use std::collections::{HashSet, VecDeque};
 
-use crate::intcode::{Intcode, State};
+use super::intcode::{Intcode, State};
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 fn process_data_a(data: &str) -> usize {
     let ints: Vec<i128> = data
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 fn run_fft(data: &[char], iterations: usize) -> String {
     let mut curr: Vec<i32> = data
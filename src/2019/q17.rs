@@ -3,9 +3,9 @@
 
 use std::collections::HashMap;
 
-use crate::intcode::{Intcode, State};
+use super::intcode::{Intcode, State};
 
-static INPUT: &str = include_str!("data/q17.data");
+input!("q17");
 
 fn process_data_a(data: &str) -> i32 {
     let ints: Vec<i128> = data
@@ -22,12 +22,10 @@ fn process_data_a(data: &str) -> i32 {
             println!("ERROR, machine not halted! {:?}", state);
         }
     }
-    let outputs = &mut machine.outputs;
     let mut board = HashMap::new();
     let mut x: i32 = 0;
     let mut y: i32 = 0;
-    while !outputs.is_empty() {
-        let cell = outputs.pop_back().unwrap() as u8 as char;
+    for cell in machine.read_string().chars() {
         match cell {
             '#' => {
                 board.insert((x, y), true);
@@ -68,8 +66,10 @@ n
         .map(|i| i.parse::<i128>().unwrap())
         .collect();
     ints[0] = 2;
-    let inputs = program.chars().map(|c| c as i128).collect();
-    let mut machine = Intcode::new(ints, inputs);
+    let mut machine = Intcode::new(ints, vec![]);
+    for line in program.lines() {
+        machine.send_line(line);
+    }
     match machine.run_tape() {
         Ok(State::Halted) => {}
         Err(code) => {
@@ -79,8 +79,8 @@ n
             println!("ERROR, machine not halted! {:?}", state);
         }
     }
-    let outputs = &mut machine.outputs;
-    outputs.pop_front().unwrap()
+    let (_, mut dust) = machine.read_ascii();
+    dust.pop().unwrap()
 }
 
 //-----------------------------------------------------
@@ -7,7 +7,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 #[derive(PartialEq, Eq)]
 enum Cell {
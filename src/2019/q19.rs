@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use crate::intcode::{Intcode, State};
+use super::intcode::{Intcode, State};
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 
 fn process_data_a(data: &str) -> i128 {
     let ints: Vec<i128> = data
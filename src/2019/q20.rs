@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, HashSet, VecDeque};
 
-static INPUT: &str = include_str!("data/q20.data");
+input!("q20");
 
 enum Cell {
     Wall,
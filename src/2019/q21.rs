@@ -1,9 +1,9 @@
 //-----------------------------------------------------
 // Setup.
 
-use crate::intcode::{Intcode, State};
+use super::intcode::{Intcode, State};
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 fn process_data_a(data: &str) -> i128 {
     let ints: Vec<i128> = data
@@ -20,7 +20,10 @@ NOT J J
 AND T J
 WALK
 ";
-    let mut machine = Intcode::new(ints, program.chars().map(|x| x as i128).collect());
+    let mut machine = Intcode::new(ints, vec![]);
+    for line in program.lines() {
+        machine.send_line(line);
+    }
     match machine.run_tape() {
         Ok(State::Halted) => {}
         Err(code) => {
@@ -30,8 +33,8 @@ WALK
             println!("ERROR, machine not halted! {:?}", state);
         }
     }
-    let outputs = &mut machine.outputs;
-    outputs[0]
+    let (_, mut damage) = machine.read_ascii();
+    damage.pop().unwrap()
 }
 
 fn process_data_b(data: &str) -> i128 {
@@ -53,7 +56,10 @@ OR E J
 AND T J
 RUN
 ";
-    let mut machine = Intcode::new(ints, program.chars().map(|x| x as i128).collect());
+    let mut machine = Intcode::new(ints, vec![]);
+    for line in program.lines() {
+        machine.send_line(line);
+    }
     match machine.run_tape() {
         Ok(State::Halted) => {}
         Err(code) => {
@@ -63,8 +69,8 @@ RUN
             println!("ERROR, machine not halted! {:?}", state);
         }
     }
-    let outputs = &mut machine.outputs;
-    outputs[0]
+    let (_, mut damage) = machine.read_ascii();
+    damage.pop().unwrap()
 }
 
 //-----------------------------------------------------
@@ -12,7 +12,7 @@ use nom::{
     multi::separated_list0,
 };
 
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 #[derive(Clone, Debug)]
 enum Instruction {
@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 static BOARD_SIZE: i32 = 5;
 
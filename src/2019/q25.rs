@@ -1,9 +1,6 @@
 //-----------------------------------------------------
 // Setup.
 
-#[cfg(test)]
-use std::io;
-
 use std::{
     cmp::Ordering,
     collections::{BinaryHeap, HashSet},
@@ -19,58 +16,12 @@ use nom::{
     sequence::terminated,
 };
 
-use crate::intcode::{Intcode, IntcodeError, State};
+use super::intcode::{Intcode, IntcodeError, State};
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 const RUN_TAPE_LIMIT: usize = 15000;
 
-#[cfg(test)]
-#[allow(dead_code)]
-fn run_machine_interactive(data: Vec<i128>) -> i128 {
-    let mut machine = Intcode::new(data, vec![]);
-    let outputs = &mut machine.outputs;
-    while let Some(output) = outputs.pop_back() {
-        if output < 255 {
-            print!("{}", output as u8 as char);
-        } else {
-            print!("{}", output);
-        }
-    }
-    loop {
-        let state = machine.run_tape_until(RUN_TAPE_LIMIT);
-        let outputs = &mut machine.outputs;
-        while let Some(output) = outputs.pop_back() {
-            if output < 255 {
-                print!("{}", output as u8 as char);
-            } else {
-                print!("{}", output);
-            }
-        }
-        match state {
-            Ok(State::Halted) => {
-                break;
-            }
-            Ok(State::WaitingForInput) => {
-                println!("waiting for input…");
-            }
-            Err(code) => {
-                println!("ERROR!!! {}", code);
-                break;
-            }
-            Ok(state) => {
-                println!("ERROR, machine not halted! {:?}", state);
-                break;
-            }
-        }
-        let mut input = String::new();
-        if io::stdin().read_line(&mut input).is_ok() {
-            machine.inputs.extend(input.chars().map(|x| x as i128));
-        }
-    }
-    0
-}
-
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 struct Room {
     name: String,
@@ -274,13 +225,9 @@ fn move_one_state(
 ) -> Option<RoomState> {
     let mut next = curr.clone();
     next.steps.push(command.to_owned());
-    next.machine
-        .inputs
-        .extend(command.chars().chain(['\n']).map(|x| x as i128));
+    next.machine.send_line(command);
     let state = next.machine.run_tape_until(RUN_TAPE_LIMIT);
-    let outputs = &mut next.machine.outputs;
-    let output: String = outputs.iter().map(|x| *x as u8 as char).rev().collect();
-    outputs.clear();
+    let output = next.machine.read_string();
 
     if let Err(IntcodeError::MachineExceededLimit { limit: _limit }) = state {
         return None;
@@ -330,9 +277,7 @@ fn move_one_state(
 fn run_machine(data: Vec<i128>) -> String {
     let mut machine = Intcode::new(data, vec![]);
     let _state = machine.run_tape_until(RUN_TAPE_LIMIT);
-    let outputs = &mut machine.outputs;
-    let output: String = outputs.iter().map(|x| *x as u8 as char).rev().collect();
-    outputs.clear();
+    let output = machine.read_string();
     let output = move_p(&output);
     if output.is_err()
         && let Err(_output) = output
@@ -4,7 +4,7 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 
 static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\d+)-(\d+) (.): (.*)").unwrap());
 
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 fn process(data: &str, values: &[(usize, usize)]) -> usize {
     let mut map: Vec<Vec<bool>> = vec![];
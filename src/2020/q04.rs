@@ -4,7 +4,7 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 static HCL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^#[0-9a-z]{6}$").unwrap());
 static HGT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([0-9a-z]+)(cm|in)$").unwrap());
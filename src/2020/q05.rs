@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 0;
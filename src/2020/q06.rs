@@ -3,7 +3,7 @@ use std::collections::HashSet;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 0;
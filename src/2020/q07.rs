@@ -13,7 +13,7 @@ use nom::{
     sequence::terminated,
 };
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct Bag {
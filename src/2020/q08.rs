@@ -14,7 +14,7 @@ use nom::{
 };
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 enum Instruction {
@@ -4,7 +4,7 @@
 use itertools::Itertools;
 use std::collections::VecDeque;
 
-static INPUT: &str = include_str!("data/q09.data");
+input!("q09");
 
 fn run_a(data: &str, limit: usize) -> usize {
     let mut previous: VecDeque<usize> = VecDeque::new();
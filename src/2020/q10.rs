@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 fn process_data_a(data: &str) -> usize {
     let mut values: Vec<usize> = data.lines().map(|x| x.parse().unwrap()).collect();
@@ -3,7 +3,7 @@
 
 use enumset::EnumSet;
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum Cell {
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 fn turn(waypoint: (isize, isize), angle: isize) -> (isize, isize) {
     match angle {
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 fn process_data_a(data: &str) -> isize {
     let mut lines = data.lines();
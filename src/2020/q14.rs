@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q14.data");
+input!("q14");
 
 static INSTRUCTION_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^mem\[([0-9]+)\] = ([0-9]+)$").unwrap());
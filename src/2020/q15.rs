@@ -3,7 +3,7 @@ use std::collections::HashMap;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 fn run(data: &str, iterations: usize) -> usize {
     let numbers: Vec<usize> = data.split(',').map(|x| x.parse().unwrap()).collect();
@@ -6,7 +6,7 @@ use std::{collections::HashMap, ops::RangeInclusive};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 // departure date: 26-827 or 843-970
 static RULE_RE: Lazy<Regex> =
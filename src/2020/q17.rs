@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q17.data");
+input!("q17");
 
 fn run(world: &HashSet<(isize, isize, isize)>) -> HashSet<(isize, isize, isize)> {
     let mut counts = HashMap::new();
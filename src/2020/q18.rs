@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 enum Op {
     Plus,
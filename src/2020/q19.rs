@@ -4,7 +4,7 @@
 use regex::Regex;
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 static USE_RE: bool = true;
 
 #[derive(Debug, Clone)]
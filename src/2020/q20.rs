@@ -6,7 +6,7 @@ use std::{
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q20.data");
+input!("q20");
 
 static SEA_MONSTER: [(usize, usize); 15] = [
     (0, 18),
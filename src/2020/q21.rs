@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 // mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
 static FOOD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(.*) \(contains (.*)\)$").unwrap());
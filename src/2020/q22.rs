@@ -3,7 +3,7 @@
 
 use std::collections::{HashSet, VecDeque};
 
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 fn process_data_a(data: &str) -> usize {
     let mut players: [VecDeque<usize>; 2] = [VecDeque::new(), VecDeque::new()];
@@ -2,7 +2,7 @@
 // Setup.
 
 use itertools::Itertools;
-static INPUT: &str = include_str!("data/q23.data");
+input!("q23");
 
 #[allow(unused)]
 fn print_cups(cups: &[usize], first: usize) {
@@ -5,7 +5,7 @@ use once_cell::sync::Lazy;
 // Setup.
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 static DIR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(e|se|sw|w|nw|ne)").unwrap());
 
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 fn process_data_a(data: &str) -> usize {
     let mut lines = data.lines();
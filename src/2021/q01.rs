@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q01.data");
+input!("q01");
 
 fn body(data: &[i32], skip: usize) -> usize {
     data.iter()
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 
 fn process_data_a(data: &str) -> i32 {
     let mut position = (0, 0);
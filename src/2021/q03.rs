@@ -3,7 +3,7 @@ use itertools::Itertools;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 fn process_data_a(data: &str) -> i32 {
     let mut bits = vec![];
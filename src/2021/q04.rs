@@ -6,7 +6,7 @@ use std::{
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 type Board = Vec<Vec<(u32, bool)>>;
 type Location = (usize, usize, usize);
@@ -5,7 +5,7 @@ use nom::{IResult, Parser, bytes::complete::tag, character::complete::u64};
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 fn parse(i: &str) -> IResult<&str, (u64, u64, u64, u64)> {
     let (input, (mut x1, _, mut y1, _, mut x2, _, mut y2)) =
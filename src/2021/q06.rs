@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 fn process_data_a(data: &str) -> u64 {
     let line: Vec<usize> = data.trim().split(',').map(|x| x.parse().unwrap()).collect();
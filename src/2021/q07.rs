@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 fn process_data_a(data: &str) -> i64 {
     let line: Vec<i64> = data.trim().split(',').map(|x| x.parse().unwrap()).collect();
@@ -4,7 +4,7 @@
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 0;
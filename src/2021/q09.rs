@@ -3,7 +3,7 @@
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q09.data");
+input!("q09");
 
 fn lowest_neighbour(i: usize, j: usize, map: &[Vec<u8>]) -> u8 {
     let mut rv = u8::MAX;
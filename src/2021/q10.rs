@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 0;
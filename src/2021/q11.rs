@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 fn increment_neighbours(board: &mut [Vec<(u8, bool)>], x: i8, y: i8) {
     let i_low: i8 = if x == 0 { 0 } else { -1 };
@@ -7,7 +7,7 @@ use std::{
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 fn process_data_a(data: &str) -> usize {
     let mut rooms: HashMap<&str, HashSet<&str>> = HashMap::new();
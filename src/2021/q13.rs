@@ -1,12 +1,12 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 
-use aoc::letters::recognize_letters;
+use aoc::letters::read_letters;
 
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 fn fold(coord: (&str, usize), grid: HashSet<(usize, usize)>) -> HashSet<(usize, usize)> {
     let mut rv = HashSet::new();
@@ -49,7 +49,8 @@ fn process_data_a(data: &str) -> usize {
     grid.len()
 }
 
-fn process_data_b(data: &str) -> String {
+/// The paper after all the folds, and how wide it is.
+fn paper(data: &str) -> (Vec<bool>, usize) {
     let mut grid = HashSet::new();
     let mut folds = vec![];
     let mut getting_points = true;
@@ -81,7 +82,12 @@ fn process_data_b(data: &str) -> String {
             image.push(grid.contains(&(x, y)));
         }
     }
-    recognize_letters(&image)
+    (image, max_x + 1)
+}
+
+fn process_data_b(data: &str) -> String {
+    let (image, width) = paper(data);
+    read_letters(&image, width)
 }
 
 //-----------------------------------------------------
@@ -124,11 +130,11 @@ fn a() {
 
 #[test]
 fn b() {
+    use aoc::letters::draw_image;
     use pretty_assertions::assert_eq;
 
-    assert_eq!(
-        process_data_b(indoc!(
-            "6,10
+    let (image, width) = paper(indoc!(
+        "6,10
     0,14
     9,10
     0,3
@@ -150,15 +156,16 @@ fn b() {
     fold along y=7
     fold along x=5
     "
-        )),
+    ));
+    assert_eq!(
+        draw_image(&image, width),
         indoc!(
-            "████ 
-        ██   
-         ██  
-          ██ 
-           █ 
-        ████ 
-        █"
+            "█████
+    █   █
+    █   █
+    █   █
+    █████
+    "
         )
     );
 }
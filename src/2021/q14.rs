@@ -5,7 +5,7 @@ use itertools::Itertools;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q14.data");
+input!("q14");
 
 // For each pair of characters,
 //   Get the two new pairs of characters, and set their count to the count of the original pair.
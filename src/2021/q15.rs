@@ -3,7 +3,7 @@ use std::{cmp::Ordering, collections::BinaryHeap};
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct State {
@@ -10,7 +10,7 @@ use nom::{
     error::{ErrorKind, make_error},
 };
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 #[derive(Debug, Clone)]
 struct InstructionV2 {
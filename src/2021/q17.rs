@@ -4,7 +4,7 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q17.data");
+input!("q17");
 
 // target area: x=20..30, y=-10..-5
 static AREA_RE: Lazy<Regex> = Lazy::new(|| {
@@ -8,7 +8,7 @@ use itertools::Itertools;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Character {
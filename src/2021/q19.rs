@@ -15,7 +15,7 @@ use nom::{
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 
 type TransformFn = fn((i32, i32, i32)) -> (i32, i32, i32);
 const DIRECTIONS: [TransformFn; 24] = [
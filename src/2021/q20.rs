@@ -3,7 +3,7 @@ use std::iter::repeat_n;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q20.data");
+input!("q20");
 
 fn parse_algorithm(data: &str) -> [bool; 512] {
     let mut rv = [false; 512];
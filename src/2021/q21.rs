@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 // Player 1 starting position: 5
 static START_RE: Lazy<Regex> =
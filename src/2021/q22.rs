@@ -6,7 +6,7 @@ use std::ops::RangeInclusive;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 // on x=10..12,y=10..12,z=10..12
 static LINE_RE: Lazy<Regex> = Lazy::new(|| {
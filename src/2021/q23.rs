@@ -7,7 +7,7 @@ use std::{
     iter,
 };
 
-static INPUT: &str = include_str!("data/q23.data");
+input!("q23");
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Cell {
@@ -13,7 +13,7 @@ use nom::{
     sequence::terminated,
 };
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 #[derive(Debug)]
 enum Instruction {
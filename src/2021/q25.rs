@@ -3,7 +3,7 @@ use std::collections::HashSet;
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 type Positions = HashSet<(usize, usize)>;
 
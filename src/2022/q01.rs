@@ -3,7 +3,7 @@
 
 use std::collections::BinaryHeap;
 
-static INPUT: &str = include_str!("data/q01.data");
+input!("q01");
 
 fn process_data_a(data: &str) -> usize {
     let mut elf = 0;
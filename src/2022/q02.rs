@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 
 #[derive(Clone)]
 enum Move {
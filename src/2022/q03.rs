@@ -3,7 +3,7 @@
 
 use std::collections::{HashSet, hash_map::RandomState};
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 0;
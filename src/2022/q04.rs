@@ -3,7 +3,7 @@
 
 use std::ops::RangeInclusive;
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 fn get_segment(elves: &str) -> RangeInclusive<usize> {
     let (start, end) = elves.split_once('-').unwrap();
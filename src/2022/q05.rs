@@ -13,7 +13,7 @@ use nom::{
 type Board = Vec<Vec<char>>;
 type Move = (usize, usize, usize);
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 fn cell(i: &str) -> IResult<&str, Option<char>> {
     let (input, board) = alt((
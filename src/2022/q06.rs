@@ -3,7 +3,7 @@
 
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 fn process(data: &str, size: usize) -> usize {
     let mut rv = 0;
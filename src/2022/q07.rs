@@ -14,7 +14,7 @@ use nom::{
     sequence::{preceded, separated_pair},
 };
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 #[derive(Debug)]
 enum Line {
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 fn visible(trees: &[Vec<u32>], row: usize, col: usize) -> bool {
     if row == 0 || col == 0 || row == trees.len() - 1 || col == trees[row].len() - 1 {
@@ -11,7 +11,7 @@ use nom::{
     sequence::separated_pair,
 };
 
-static INPUT: &str = include_str!("data/q09.data");
+input!("q09");
 
 #[derive(Debug)]
 struct Move {
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-use aoc::letters::recognize_letters;
+use aoc::letters::read_letters;
 
 use nom::{
     IResult, Parser,
@@ -12,7 +12,7 @@ use nom::{
     sequence::preceded,
 };
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 #[derive(Debug)]
 enum Instruction {
@@ -76,7 +76,8 @@ fn process_data_a(data: &str) -> i64 {
     rv
 }
 
-fn process_data_b(data: &str) -> String {
+/// What the CRT draws, forty pixels to a row.
+fn screen(data: &str) -> Vec<bool> {
     let mut letters = vec![];
     let mut x = 1;
     let mut cycle = 0;
@@ -91,7 +92,11 @@ fn process_data_b(data: &str) -> String {
         }
         instruction.execute(&mut x);
     }
-    recognize_letters(&letters)
+    letters
+}
+
+fn process_data_b(data: &str) -> String {
+    read_letters(&screen(data), 40)
 }
 
 //-----------------------------------------------------
@@ -259,11 +264,13 @@ fn a() {
 
 #[test]
 fn b() {
+    use aoc::letters::draw_image;
     use pretty_assertions::assert_eq;
 
     assert_eq!(
-        process_data_b(indoc!(
-            "addx 15
+        draw_image(
+            &screen(indoc!(
+                "addx 15
     addx -11
     addx 6
     addx -3
@@ -410,7 +417,9 @@ fn b() {
     noop
     noop
     "
-        )),
+            )),
+            40
+        ),
         indoc!(
             "██  ██  ██  ██  ██  ██  ██  ██  ██  ██  
     ███   ███   ███   ███   ███   ███   ███ 
@@ -12,7 +12,7 @@ use nom::{
     sequence::{delimited, preceded},
 };
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 #[derive(Debug)]
 enum Operation {
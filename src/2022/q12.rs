@@ -5,7 +5,7 @@ use std::collections::{BinaryHeap, HashSet};
 
 use enumset::{EnumSet, EnumSetType};
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 #[derive(Debug, EnumSetType)]
 enum Direction {
@@ -16,7 +16,7 @@ use nom::{
     sequence::{delimited, terminated},
 };
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum Node {
@@ -3,7 +3,7 @@
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q14.data");
+input!("q14");
 
 type Coord = (usize, usize);
 
@@ -11,7 +11,7 @@ use nom::{
 };
 use range_set::RangeSet;
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 type Coord = (i64, i64);
 
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 use std::{
     cmp::Ordering,
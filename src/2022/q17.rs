@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, VecDeque};
 
-static INPUT: &str = include_str!("data/q17.data");
+input!("q17");
 
 use once_cell::sync::Lazy;
 
@@ -5,7 +5,7 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 fn adjacent(a: &[usize], b: &[usize]) -> bool {
     let mut diffs = 0;
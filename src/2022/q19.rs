@@ -11,7 +11,7 @@ use nom::{
 };
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 
 #[derive(Debug)]
 struct Recipe {
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q20.data");
+input!("q20");
 
 fn process(data: &str, key: i64, reps: usize) -> i64 {
     let mut values: Vec<(usize, i64)> = data
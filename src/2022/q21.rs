@@ -11,7 +11,7 @@ use nom::{
     multi::separated_list1,
 };
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 #[derive(Debug, Clone)]
 enum Value {
@@ -13,7 +13,7 @@ use nom::{
 
 use once_cell::sync::Lazy;
 
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 type FaceDirection = (usize, Direction);
 
@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-static INPUT: &str = include_str!("data/q23.data");
+input!("q23");
 
 fn get_adjacent(elf: &(i32, i32)) -> Vec<(i32, i32)> {
     vec![
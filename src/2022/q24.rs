@@ -5,7 +5,7 @@ use std::collections::HashSet;
 
 use num_integer::lcm;
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Direction {
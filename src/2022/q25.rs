@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 fn from_snafu(input: &str) -> i64 {
     let mut rv = 0;
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
-static INPUT: &str = include_str!("data/q01.data");
+input!("q01");
 
 fn process_data_a(data: &str) -> u32 {
     let mut rv = 0;
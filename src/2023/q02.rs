@@ -10,7 +10,7 @@ use nom::{
     sequence::separated_pair,
 };
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 
 #[derive(Debug)]
 struct Round {
@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet};
 
 use aoc::util::{Point2, ring};
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 #[derive(Debug, Clone)]
 
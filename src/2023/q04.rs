@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 0;
@@ -9,7 +9,7 @@ use nom::{
     multi::separated_list1,
 };
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 #[derive(Debug)]
 struct Range {
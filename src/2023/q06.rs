@@ -3,7 +3,7 @@
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 1;
@@ -5,7 +5,7 @@ use std::{collections::HashMap, fmt::Display};
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 const A_CARD_ORDER: &str = "23456789TJQKA";
 const B_CARD_ORDER: &str = "J23456789TQKA";
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 use std::collections::HashMap;
 
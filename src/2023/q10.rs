@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use aoc::util::Point2;
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum Direction {
@@ -5,7 +5,7 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 fn calculate_answer(data: &str, offset: usize) -> usize {
     // Doubling should only add 1.
@@ -7,7 +7,7 @@ use rayon::{
 };
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 type Key<'a> = (&'a [Condition], &'a [usize]);
 
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use aoc::util::Point2;
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 fn parse(data: &str) -> Vec<HashMap<Point2, ()>> {
     let mut rv = vec![];
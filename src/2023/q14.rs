@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use aoc::util::Point2;
 
-static INPUT: &str = include_str!("data/q14.data");
+input!("q14");
 
 #[derive(Copy, Clone, Debug, PartialOrd, Ord, Hash, Eq, PartialEq)]
 enum Rock {
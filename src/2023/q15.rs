@@ -11,7 +11,7 @@ use nom::{
     multi::separated_list1,
 };
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 fn process_data_a(data: &str) -> usize {
     let mut rv = 0;
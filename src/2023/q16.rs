@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use aoc::util::Direction;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 #[derive(Debug, Hash, Copy, Clone, PartialEq, Eq)]
 enum Cell {
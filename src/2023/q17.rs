@@ -5,7 +5,7 @@ use std::collections::{BinaryHeap, HashMap};
 
 use aoc::util::Direction;
 
-static INPUT: &str = include_str!("data/q17.data");
+input!("q17");
 
 fn parse(data: &str) -> Vec<Vec<i32>> {
     let mut rv = vec![];
@@ -10,7 +10,7 @@ use nom::{
     multi::separated_list1,
 };
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 type Instruction = (Direction, usize);
 
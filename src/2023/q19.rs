@@ -11,7 +11,7 @@ use nom::{
     multi::{many1, separated_list1},
 };
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 
 #[derive(Debug)]
 enum Rule<'a> {
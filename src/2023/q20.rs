@@ -13,7 +13,7 @@ use nom::{
 };
 use num_integer::lcm;
 
-static INPUT: &str = include_str!("data/q20.data");
+input!("q20");
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum GateType<'a> {
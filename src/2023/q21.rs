@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet};
 
 use aoc::util::{Direction, Point2};
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 fn parse(data: &str) -> (HashSet<Point2>, (Point2, Point2), Point2) {
     let mut board = HashSet::new();
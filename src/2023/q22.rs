@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use aoc::util::Point3;
 
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 #[derive(Clone, Debug)]
 struct Block {
@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use aoc::util::{Direction, Point2, point_to_index};
 
-static INPUT: &str = include_str!("data/q23.data");
+input!("q23");
 
 pub struct Input {
     extra: u32,
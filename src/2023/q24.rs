@@ -13,7 +13,7 @@ use nom::{
     multi::separated_list1,
 };
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 #[derive(Debug, Clone, Copy)]
 struct Hailstone {
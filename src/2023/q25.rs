@@ -3,7 +3,7 @@
 
 use std::collections::{HashMap, HashSet, VecDeque};
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 fn parse(data: &str) -> HashMap<&str, HashSet<&str>> {
     let mut rv: HashMap<&str, HashSet<&str>> = HashMap::new();
@@ -3,7 +3,7 @@
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q02.data");
+input!("q02");
 
 fn get_level_error(numbers: &[i32]) -> Option<usize> {
     let mut direction = None;
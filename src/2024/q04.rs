@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 fn find_xmas(x: usize, y: usize, grid: &[Vec<char>]) -> usize {
     let mut rv = 0;
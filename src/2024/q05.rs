@@ -11,7 +11,7 @@ use nom::{
 type Orderings = Vec<(u32, u32)>;
 type Updates = Vec<Vec<u32>>;
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 fn page_ordering(i: &str) -> IResult<&str, (u32, u32)> {
     let (input, (first, _, second, _)) = (u32, tag("|"), u32, newline).parse(i)?;
@@ -5,7 +5,7 @@ use std::collections::HashSet;
 
 use aoc::util::{Direction, Point2};
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 type State = (
     HashSet<Point2>,
@@ -3,7 +3,7 @@
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 #[derive(Debug)]
 enum Operators {
@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use aoc::util::{Point2, in_bounds};
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 fn process_data_a(data: &str) -> usize {
     let origin = (0, 0);
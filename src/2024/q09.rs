@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q09.data");
+input!("q09");
 
 const ZERO: usize = '0' as usize;
 
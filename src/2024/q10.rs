@@ -5,7 +5,7 @@ use std::collections::HashSet;
 
 use aoc::util::{Direction, Point2};
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 fn parse(data: &str) -> (Vec<Vec<u8>>, Vec<Point2>) {
     let mut map = vec![];
@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 fn stone_count(stone: String, iterations: i32, cache: &mut HashMap<(String, i32), usize>) -> usize {
     if iterations == 0 {
@@ -9,7 +9,7 @@ use std::{
 use aoc::util::{Direction, Point2};
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 #[derive(Clone, Debug)]
 struct Region {
@@ -17,7 +17,7 @@ struct Machine {
     prize: Point2,
 }
 
-static INPUT: &str = include_str!("data/q13.data");
+input!("q13");
 
 fn button(i: &str) -> IResult<&str, Point2> {
     // Button A: X+44, Y+17\n
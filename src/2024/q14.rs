@@ -9,7 +9,7 @@ use nom::{
     multi::separated_list0,
 };
 
-static INPUT: &str = include_str!("data/q14.data");
+input!("q14");
 
 #[derive(Debug)]
 struct Robot {
@@ -11,7 +11,7 @@ use nom::{
     sequence::terminated,
 };
 
-static INPUT: &str = include_str!("data/q15.data");
+input!("q15");
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 enum Object {
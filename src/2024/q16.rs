@@ -8,7 +8,7 @@ use std::{
 
 use aoc::util::{Direction, Point2};
 
-static INPUT: &str = include_str!("data/q16.data");
+input!("q16");
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum Cell {
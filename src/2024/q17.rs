@@ -10,7 +10,7 @@ use nom::{
     sequence::preceded,
 };
 
-static INPUT: &str = include_str!("data/q17.data");
+input!("q17");
 
 #[derive(Copy, Clone, Debug)]
 enum Instruction {
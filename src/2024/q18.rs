@@ -9,7 +9,7 @@ use std::{
 use aoc::util::{Direction, Point2};
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q18.data");
+input!("q18");
 
 fn parse_data(data: &str) -> Vec<Point2> {
     let mut rv = vec![];
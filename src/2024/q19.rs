@@ -11,7 +11,7 @@ use nom::{
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q19.data");
+input!("q19");
 
 fn designs(i: &str) -> IResult<&str, Vec<String>> {
     let (input, designs) =
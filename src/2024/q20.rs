@@ -5,7 +5,7 @@ use std::{cmp::Ordering, collections::HashMap};
 
 use aoc::util::{Direction, Point2, in_bounds};
 
-static INPUT: &str = include_str!("data/q20.data");
+input!("q20");
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum Cell {
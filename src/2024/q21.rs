@@ -15,7 +15,7 @@ pub enum Move {
     Activate,
 }
 
-static INPUT: &str = include_str!("data/q21.data");
+input!("q21");
 
 fn parse(data: &str) -> Vec<(Vec<usize>, usize)> {
     let mut codes = vec![];
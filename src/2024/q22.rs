@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-static INPUT: &str = include_str!("data/q22.data");
+input!("q22");
 
 fn process_data_a(data: &str) -> usize {
     let mut monkeys: Vec<usize> = data.lines().map(|x| x.parse().unwrap()).collect();
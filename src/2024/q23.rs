@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q23.data");
+input!("q23");
 
 fn process_data_a(data: &str) -> usize {
     let mut groups = HashMap::new();
@@ -12,7 +12,7 @@ use nom::{
     multi::many1,
 };
 
-static INPUT: &str = include_str!("data/q24.data");
+input!("q24");
 
 type Initial<'a> = HashMap<&'a str, bool>;
 
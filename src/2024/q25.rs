@@ -9,7 +9,7 @@ use nom::{
     sequence::terminated,
 };
 
-static INPUT: &str = include_str!("data/q25.data");
+input!("q25");
 
 type Items = Vec<Vec<usize>>;
 
@@ -1,19 +1,5 @@
 #![feature(exact_size_is_empty)]
 
-// modules
-mod q01;
-mod q02;
-mod q03;
-mod q04;
-mod q05;
-mod q06;
-mod q07;
-mod q08;
-mod q09;
-mod q10;
-mod q11;
-mod q12;
-
 #[macro_use]
 extern crate aoc;
 // #[macro_use]
@@ -24,8 +10,9 @@ extern crate indoc;
 // #[macro_use]
 // extern crate itertools;
 
-fn main() {
-    let days = q_vec!(q01, q02, q03, q04, q05, q06, q07, q08, q09, q10, q11, q12,);
+#[path = "mod.rs"]
+mod year;
 
-    aoc::main(&days)
+fn main() {
+    aoc::main(2025, &year::days())
 }
@@ -0,0 +1,17 @@
+// modules
+mod q01;
+mod q02;
+mod q03;
+mod q04;
+mod q05;
+mod q06;
+mod q07;
+mod q08;
+mod q09;
+mod q10;
+mod q11;
+mod q12;
+
+pub fn days() -> Vec<Box<dyn aoc::Day>> {
+    q_vec!(q01, q02, q03, q04, q05, q06, q07, q08, q09, q10, q11, q12,)
+}
@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/q03.data");
+input!("q03");
 
 fn find_highest(line: &[u8], position: usize) -> (usize, u8) {
     let mut rv = 0;
@@ -3,7 +3,7 @@
 
 use std::collections::HashSet;
 
-static INPUT: &str = include_str!("data/q04.data");
+input!("q04");
 
 fn valid(roll: &(i32, i32), map: &HashSet<(i32, i32)>) -> bool {
     let mut count = 0;
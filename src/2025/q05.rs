@@ -13,7 +13,7 @@ use nom::{
 
 type Values = u64;
 
-static INPUT: &str = include_str!("data/q05.data");
+input!("q05");
 
 fn range(i: &str) -> IResult<&str, Range<Values>> {
     let (input, (start, _, end)) = (value_parser, tag("-"), value_parser).parse(i)?;
@@ -10,7 +10,7 @@ use nom::{
     sequence::{preceded, terminated},
 };
 
-static INPUT: &str = include_str!("data/q06.data");
+input!("q06");
 
 type Value = u64;
 
@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet};
 
 use aoc::util::Point2;
 
-static INPUT: &str = include_str!("data/q07.data");
+input!("q07");
 
 fn parse(data: &str) -> (Point2, Point2, HashSet<Point2>) {
     let mut start = (0, 0);
@@ -5,7 +5,7 @@ use aoc::util::{Point3, distance_3};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
-static INPUT: &str = include_str!("data/q08.data");
+input!("q08");
 
 type Circuits = Vec<HashSet<Point3>>;
 type Distances = HashMap<i64, Vec<(Point3, Point3)>>;
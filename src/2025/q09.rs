@@ -3,7 +3,7 @@
 
 use itertools::Itertools;
 
-static INPUT: &str = include_str!("data/q09.data");
+input!("q09");
 
 fn process_data_a(data: &str) -> i64 {
     let mut rv = 0;
@@ -13,7 +13,7 @@ use nom::{
     sequence::{delimited, terminated},
 };
 
-static INPUT: &str = include_str!("data/q10.data");
+input!("q10");
 
 type Value = u32;
 type Line = (Value, Vec<(Value, Vec<Value>)>, Vec<Value>);
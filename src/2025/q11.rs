@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-static INPUT: &str = include_str!("data/q11.data");
+input!("q11");
 
 fn parse(data: &str) -> HashMap<&str, Vec<&str>> {
     let mut wires = HashMap::new();
@@ -9,7 +9,7 @@ use nom::{
     sequence::{preceded, terminated},
 };
 
-static INPUT: &str = include_str!("data/q12.data");
+input!("q12");
 
 #[derive(Debug)]
 struct Present {
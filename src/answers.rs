@@ -0,0 +1,90 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::PathBuf,
+};
+
+use custom_error::custom_error;
+
+use crate::{Part, Solution, input::data_path};
+
+custom_error! { pub AnswersError
+    IOError{source: std::io::Error} = "io error: {source}",
+    JSON{source: serde_json::error::Error} = "json error: {source}",
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => f.write_str("UNKNOWN"),
+        }
+    }
+}
+
+/// The recorded answers for a year, stored as `answers.json` next to the year's inputs.
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    expected: BTreeMap<String, String>,
+}
+
+impl Answers {
+    pub fn path(year: u32) -> PathBuf {
+        data_path(&year.to_string(), "answers.json")
+    }
+
+    /// Load the answers for `year`, or an empty set if none have been recorded yet.
+    pub fn load(year: u32) -> Result<Self, AnswersError> {
+        let path = Answers::path(year);
+        let expected = if path.is_file() {
+            serde_json::from_reader(BufReader::new(File::open(&path)?))?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Answers { path, expected })
+    }
+
+    fn key(day: &str, part: Part) -> String {
+        format!("{}{}", day, part).to_lowercase()
+    }
+
+    pub fn check(&self, solution: &Solution) -> Verdict {
+        match self
+            .expected
+            .get(&Answers::key(&solution.day, solution.part))
+        {
+            None => Verdict::Unknown,
+            Some(expected) if *expected == solution.answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+
+    pub fn record(&mut self, solution: &Solution) {
+        self.set(&solution.day, solution.part, solution.answer.to_string());
+    }
+
+    /// Record `answer` as the expected answer, like one the server has accepted.
+    pub fn set(&mut self, day: &str, part: Part, answer: String) {
+        self.expected.insert(Answers::key(day, part), answer);
+    }
+
+    pub fn save(&self) -> Result<(), AnswersError> {
+        let mut writer = BufWriter::new(File::create(&self.path)?);
+        serde_json::to_writer_pretty(&mut writer, &self.expected)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate aoc;
+#[macro_use]
+extern crate enumset;
+#[allow(unused_imports)]
+#[macro_use]
+extern crate indoc;
+#[macro_use]
+extern crate itertools;
+#[macro_use]
+extern crate maplit;
+
+// years
+#[path = "2015/mod.rs"]
+mod y2015;
+#[path = "2016/mod.rs"]
+mod y2016;
+#[path = "2017/mod.rs"]
+mod y2017;
+#[path = "2018/mod.rs"]
+mod y2018;
+#[path = "2019/mod.rs"]
+mod y2019;
+#[path = "2020/mod.rs"]
+mod y2020;
+#[path = "2021/mod.rs"]
+mod y2021;
+#[path = "2022/mod.rs"]
+mod y2022;
+#[path = "2023/mod.rs"]
+mod y2023;
+#[path = "2024/mod.rs"]
+mod y2024;
+#[path = "2025/mod.rs"]
+mod y2025;
+
+fn main() {
+    let years = vec![
+        (2015, y2015::days()),
+        (2016, y2016::days()),
+        (2017, y2017::days()),
+        (2018, y2018::days()),
+        (2019, y2019::days()),
+        (2020, y2020::days()),
+        (2021, y2021::days()),
+        (2022, y2022::days()),
+        (2023, y2023::days()),
+        (2024, y2024::days()),
+        (2025, y2025::days()),
+    ];
+
+    aoc::main_years(&years)
+}
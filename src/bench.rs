@@ -0,0 +1,115 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+use serde_json::{Value, json};
+
+use crate::{Answer, Day, Part};
+
+/// Timings for repeated runs of one part of a day, in the spirit of `hyperfine`.
+#[derive(Clone, Debug)]
+pub struct Benchmark {
+    pub year: u32,
+    pub day: String,
+    pub part: Part,
+    pub answer: Answer,
+    pub times: Vec<Duration>,
+}
+
+impl Benchmark {
+    /// Run `part` of `day` `warmup` times without measuring, then `runs` more times.
+    pub fn run(year: u32, day: &dyn Day, part: Part, warmup: usize, runs: usize) -> Self {
+        for _ in 0..warmup {
+            day.run(part);
+        }
+        let mut times = vec![];
+        let mut answer = None;
+        for _ in 0..runs.max(1) {
+            let solution = day.run(part);
+            times.push(solution.elapsed);
+            answer = Some(solution.answer);
+        }
+        Benchmark {
+            year,
+            day: day.number(),
+            part,
+            answer: answer.unwrap(),
+            times,
+        }
+    }
+
+    fn seconds(&self) -> impl Iterator<Item = f64> + '_ {
+        self.times.iter().map(|time| time.as_secs_f64())
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_secs_f64(self.seconds().sum::<f64>() / self.times.len() as f64)
+    }
+
+    /// The sample standard deviation of the run times.
+    pub fn std_dev(&self) -> Duration {
+        if self.times.len() < 2 {
+            return Duration::ZERO;
+        }
+        let mean = self.mean().as_secs_f64();
+        let variance = self
+            .seconds()
+            .map(|time| (time - mean).powi(2))
+            .sum::<f64>()
+            / (self.times.len() - 1) as f64;
+        Duration::from_secs_f64(variance.sqrt())
+    }
+
+    pub fn min(&self) -> Duration {
+        self.times.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.times.iter().copied().max().unwrap_or_default()
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part.to_string(),
+            "answer": self.answer.to_string(),
+            "runs": self.times.len(),
+            "mean": self.mean().as_secs_f64(),
+            "stddev": self.std_dev().as_secs_f64(),
+            "min": self.min().as_secs_f64(),
+            "max": self.max().as_secs_f64(),
+        })
+    }
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{}{}: {:>12.3?} ± {:>10.3?}    [Range: {:.3?} … {:.3?}]    {} runs",
+            self.day,
+            self.part,
+            self.mean(),
+            self.std_dev(),
+            self.min(),
+            self.max(),
+            self.times.len()
+        )
+    }
+}
+
+/// Write the benchmarks as pretty-printed JSON, so runs can be diffed between commits.
+pub fn write_report(path: &Path, benchmarks: &[Benchmark]) -> std::io::Result<()> {
+    let report = json!({
+        "results": benchmarks.iter().map(Benchmark::to_json).collect::<Vec<_>>(),
+    });
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, &report)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+
+/// The first year there was an Advent of Code.
+pub const FIRST_YEAR: i32 = 2015;
+
+/// How many puzzles `year` has. From 2025 on there are only twelve.
+pub fn puzzle_count(year: i32) -> u32 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Whether `year` has a puzzle on `day`.
+pub fn has_puzzle(year: i32, day: u32) -> bool {
+    year >= FIRST_YEAR && (1..=puzzle_count(year)).contains(&day)
+}
+
+/// Whether stars for `day` scored any points. A couple of days didn't, after server trouble.
+pub fn awards_points(year: i32, day: u32) -> bool {
+    !matches!((year, day), (2018, 6) | (2020, 1))
+}
+
+/// When the puzzle for `day` unlocks: midnight, US Eastern time, on that day of December.
+pub fn unlock_time(year: i32, day: u32) -> Option<DateTime<Utc>> {
+    Utc.with_ymd_and_hms(year, 12, day, 5, 0, 0).single()
+}
+
+/// The puzzles in `year` that have unlocked by `now`.
+pub fn released_days(year: i32, now: DateTime<Utc>) -> Vec<u32> {
+    (1..=puzzle_count(year))
+        .filter(|&day| unlock_time(year, day).is_some_and(|time| time <= now))
+        .collect()
+}
+
+/// The next puzzle in `year` to unlock after `now`, if there are any left.
+pub fn next_unlock(year: i32, now: DateTime<Utc>) -> Option<(u32, DateTime<Utc>)> {
+    (1..=puzzle_count(year))
+        .filter_map(|day| Some((day, unlock_time(year, day)?)))
+        .find(|&(_, time)| time > now)
+}
+
+/// The most recent puzzle to unlock by `now`, falling back to the end of last year's
+/// puzzles before December.
+pub fn latest_puzzle(now: DateTime<Utc>) -> (i32, u32) {
+    let year = now.year();
+    match released_days(year, now).last() {
+        Some(&day) => (year, day),
+        None => (year - 1, puzzle_count(year - 1)),
+    }
+}
+
+#[test]
+fn test_calendar() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!((puzzle_count(2024), puzzle_count(2025)), (25, 12));
+    assert!(has_puzzle(2024, 25));
+    assert!(!has_puzzle(2025, 13));
+    assert!(!has_puzzle(2014, 1));
+    assert!(!awards_points(2020, 1));
+    assert!(awards_points(2020, 2));
+
+    let now = Utc.with_ymd_and_hms(2025, 12, 3, 4, 59, 59).unwrap();
+    assert_eq!(released_days(2025, now), vec![1, 2]);
+    assert_eq!(
+        next_unlock(2025, now),
+        Some((3, unlock_time(2025, 3).unwrap()))
+    );
+    assert_eq!(released_days(2024, now).len(), 25);
+
+    let now = Utc.with_ymd_and_hms(2025, 12, 12, 5, 0, 0).unwrap();
+    assert_eq!(released_days(2025, now).len(), 12);
+    assert_eq!(next_unlock(2025, now), None);
+    assert_eq!(latest_puzzle(now), (2025, 12));
+    assert_eq!(
+        latest_puzzle(Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap()),
+        (2025, 12)
+    );
+}
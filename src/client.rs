@@ -0,0 +1,371 @@
+use std::{
+    env::{var, var_os},
+    fs::{self, create_dir_all, remove_file},
+    path::PathBuf,
+    sync::Mutex,
+    thread::sleep,
+    time::{Duration, Instant, SystemTime},
+};
+
+use custom_error::custom_error;
+use reqwest::{
+    StatusCode,
+    blocking::{RequestBuilder, Response},
+    header::{COOKIE, RETRY_AFTER, USER_AGENT},
+};
+
+use crate::session::{Accounts, Session, SessionError, is_logged_out};
+
+custom_error! { pub ClientError
+    IOError{source: std::io::Error} = "io error",
+    Request{source: reqwest::Error} = "request error",
+    Session{source: SessionError} = "{source}",
+    Status{url: String, status: StatusCode} = "\"{url}\" returned {status}.",
+    ExpiredSession{account: String} = "The session for {account} has expired, since the server asked us to log in. Copy a new one from adventofcode.com's session cookie.",
+    Offline{url: String} = "Not fetching \"{url}\", since we're offline.",
+}
+
+/// The real server. Set `AOC_BASE_URL` to use another one, like a local test server.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Who's asking, so the server's operators can get in touch. Set `AOC_USER_AGENT` to change it.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " by ",
+    env!("CARGO_PKG_AUTHORS")
+);
+
+/// Where cached responses are kept, one file per URL: `AOC_CACHE` if it's set, otherwise
+/// `adventofcode` in the user's cache directory, or `.cache` if we can't find that.
+pub fn cache_dir() -> PathBuf {
+    if let Some(path) = var_os("AOC_CACHE") {
+        return PathBuf::from(path);
+    }
+    var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .map_or_else(
+            || PathBuf::from(".cache"),
+            |cache| cache.join("adventofcode"),
+        )
+}
+
+/// The shortest gap between two requests, from anywhere in the program.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// When the last request went out. It's shared by every `Client`, so that making a new one
+/// doesn't get around the rate limit.
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+fn wait_for_turn(interval: Duration) {
+    let mut last = LAST_REQUEST
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    if let Some(last) = *last {
+        let wait = interval.saturating_sub(last.elapsed());
+        if !wait.is_zero() {
+            sleep(wait);
+        }
+    }
+    *last = Some(Instant::now());
+}
+
+/// How long the server asked us to wait, if it did.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let seconds = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    seconds.trim().parse().ok().map(Duration::from_secs)
+}
+
+/// Talks to the Advent of Code server for all the tools, politely.
+#[derive(Clone, Debug)]
+pub struct Client {
+    http: reqwest::blocking::Client,
+    /// `None` when we're offline, and only have the cache to go on.
+    session: Option<Session>,
+    base_url: String,
+    user_agent: String,
+    interval: Duration,
+    cache_dir: PathBuf,
+    retries: u32,
+    backoff: Duration,
+    retry_statuses: Vec<StatusCode>,
+}
+
+impl Client {
+    /// A client for the default session, from `AOC_SESSION` or the config file.
+    pub fn new() -> Result<Self, ClientError> {
+        Client::for_account(None)
+    }
+
+    /// A client for one of the accounts in the config file, using the `AOC_BASE_URL`
+    /// and `AOC_USER_AGENT` overrides if they're set.
+    pub fn for_account(account: Option<&str>) -> Result<Self, ClientError> {
+        Ok(Client::with_session(Some(
+            Accounts::load()?.session(account)?,
+        )))
+    }
+
+    /// A client that never sends anything, so it only ever gets answers from the cache.
+    pub fn offline() -> Self {
+        Client::with_session(None)
+    }
+
+    fn with_session(session: Option<Session>) -> Self {
+        let client = Client {
+            http: reqwest::blocking::Client::new(),
+            session,
+            base_url: BASE_URL.to_owned(),
+            user_agent: var("AOC_USER_AGENT").unwrap_or_else(|_| DEFAULT_USER_AGENT.to_owned()),
+            interval: DEFAULT_INTERVAL,
+            cache_dir: cache_dir(),
+            retries: 3,
+            backoff: Duration::from_secs(5),
+            retry_statuses: vec![StatusCode::TOO_MANY_REQUESTS],
+        };
+        match var("AOC_BASE_URL") {
+            Ok(base_url) => client.base_url(&base_url),
+            Err(_) => client,
+        }
+    }
+
+    /// Whose session this client is using, if it isn't offline.
+    pub fn account(&self) -> Option<&str> {
+        self.session
+            .as_ref()
+            .map(|session| session.account.as_str())
+    }
+
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_owned();
+        self
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn cache_dir(mut self, cache_dir: PathBuf) -> Self {
+        self.cache_dir = cache_dir;
+        self
+    }
+
+    /// Try failed requests `retries` more times, waiting `backoff` before the first retry,
+    /// and twice as long before each one after that.
+    pub fn retries(mut self, retries: u32, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Also retry when the server answers with `status`. Server errors and
+    /// "Too Many Requests" are always retried.
+    pub fn retry_on(mut self, status: StatusCode) -> Self {
+        self.retry_statuses.push(status);
+        self
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Where the response for `path` is cached. Each server gets its own directory,
+    /// so a test server's responses don't get mixed up with the real ones.
+    pub fn cache_path(&self, path: &str) -> PathBuf {
+        let host = self
+            .base_url
+            .split_once("://")
+            .map_or(self.base_url.as_str(), |(_, host)| host);
+        let mut rv = self.cache_dir.join(host.replace([':', '/'], "_"));
+        rv.extend(
+            path.split(['/', '?', '&'])
+                .filter(|part| !part.is_empty() && *part != ".."),
+        );
+        rv
+    }
+
+    /// How long to wait before retrying for the `attempt`th time.
+    fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(1 << attempt.min(16))
+    }
+
+    /// Whether to try again after the server answered with `status`. A request that isn't
+    /// `idempotent`, like submitting an answer, may already have been acted on, so it's only
+    /// retried when the server turned it away and said how long to wait.
+    fn should_retry(&self, status: StatusCode, idempotent: bool, wait: Option<Duration>) -> bool {
+        if idempotent {
+            status.is_server_error() || self.retry_statuses.contains(&status)
+        } else {
+            status == StatusCode::TOO_MANY_REQUESTS && wait.is_some()
+        }
+    }
+
+    fn send(
+        &self,
+        url: &str,
+        idempotent: bool,
+        build: impl Fn(&reqwest::blocking::Client) -> RequestBuilder,
+    ) -> Result<String, ClientError> {
+        let Some(session) = &self.session else {
+            return Err(ClientError::Offline {
+                url: url.to_owned(),
+            });
+        };
+        let mut attempt = 0;
+        loop {
+            wait_for_turn(self.interval);
+            let result = build(&self.http)
+                .header(USER_AGENT, &self.user_agent)
+                .header(COOKIE, format!("session={}", session.token))
+                .send();
+            let last_attempt = attempt >= self.retries;
+            let delay = match result {
+                Ok(response) => {
+                    let status = response.status();
+                    let wait = retry_after(&response);
+                    let body = response.text()?;
+                    if is_logged_out(&body) {
+                        return Err(ClientError::ExpiredSession {
+                            account: session.account.clone(),
+                        });
+                    }
+                    if status.is_success() {
+                        return Ok(body);
+                    }
+                    if last_attempt || !self.should_retry(status, idempotent, wait) {
+                        return Err(ClientError::Status {
+                            url: url.to_owned(),
+                            status,
+                        });
+                    }
+                    wait.unwrap_or_else(|| self.delay(attempt))
+                }
+                Err(error) => {
+                    // A request that timed out may have got through anyway.
+                    if last_attempt || !idempotent || !(error.is_timeout() || error.is_connect()) {
+                        return Err(error.into());
+                    }
+                    self.delay(attempt)
+                }
+            };
+            sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    /// Make sure the session still works, by fetching the front page and seeing
+    /// whether it asks us to log in.
+    pub fn check_session(&self) -> Result<(), ClientError> {
+        self.get("/").map(|_| ())
+    }
+
+    /// Fetch `path`, like `/2025/day/1/input`.
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = self.url(path);
+        self.send(&url, true, |http| http.get(&url))
+    }
+
+    /// Post `form` to `path`. This isn't retried unless the server asks us to slow down,
+    /// since answering twice could count as a wrong answer.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = self.url(path);
+        self.send(&url, false, |http| http.post(&url).form(form))
+    }
+
+    /// Whether there's a cached response for `path` that's no older than `ttl`.
+    pub fn is_cached(&self, path: &str, ttl: Duration) -> bool {
+        fs::metadata(self.cache_path(path))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age <= ttl)
+    }
+
+    /// The cached response for `path`, however old it is, without fetching anything.
+    pub fn cached(&self, path: &str) -> Option<String> {
+        fs::read_to_string(self.cache_path(path)).ok()
+    }
+
+    /// Fetch `path`, unless we already fetched it within the last `ttl`.
+    pub fn get_cached(&self, path: &str, ttl: Duration) -> Result<String, ClientError> {
+        let cache = self.cache_path(path);
+        if self.is_cached(path, ttl) {
+            return Ok(fs::read_to_string(cache)?);
+        }
+        let body = self.get(path)?;
+        if let Some(parent) = cache.parent() {
+            create_dir_all(parent)?;
+        }
+        fs::write(&cache, &body)?;
+        Ok(body)
+    }
+
+    /// Throw away the cached response for `path`, say because it turned out to be garbage.
+    pub fn uncache(&self, path: &str) -> Result<(), ClientError> {
+        let cache = self.cache_path(path);
+        if cache.is_file() {
+            remove_file(cache)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_client() {
+    use pretty_assertions::assert_eq;
+
+    let client = Client {
+        http: reqwest::blocking::Client::new(),
+        session: None,
+        base_url: BASE_URL.to_owned(),
+        user_agent: DEFAULT_USER_AGENT.to_owned(),
+        interval: Duration::ZERO,
+        cache_dir: PathBuf::from("cache"),
+        retries: 3,
+        backoff: Duration::from_secs(5),
+        retry_statuses: vec![StatusCode::TOO_MANY_REQUESTS],
+    };
+    assert_eq!(
+        client.url("/2025/day/1/input"),
+        "https://adventofcode.com/2025/day/1/input"
+    );
+    assert_eq!(
+        client.cache_path("/2025/leaderboard/private/view/70644.json"),
+        PathBuf::from("cache/adventofcode.com/2025/leaderboard/private/view/70644.json")
+    );
+    assert_eq!(
+        client.delay(0) + client.delay(1) + client.delay(2),
+        Duration::from_secs(35)
+    );
+    assert!(client.should_retry(StatusCode::BAD_GATEWAY, true, None));
+    assert!(!client.should_retry(StatusCode::NOT_FOUND, true, None));
+    let wait = Some(Duration::from_secs(60));
+    assert!(!client.should_retry(StatusCode::BAD_GATEWAY, false, wait));
+    assert!(!client.should_retry(StatusCode::TOO_MANY_REQUESTS, false, None));
+    assert!(client.should_retry(StatusCode::TOO_MANY_REQUESTS, false, wait));
+
+    let client = client
+        .base_url("http://localhost:8000/")
+        .retry_on(StatusCode::NOT_FOUND);
+    assert_eq!(
+        client.url("/2025/day/1"),
+        "http://localhost:8000/2025/day/1"
+    );
+    assert_eq!(
+        client.cache_path("/../2025/day/1"),
+        PathBuf::from("cache/localhost_8000/2025/day/1")
+    );
+    assert!(client.should_retry(StatusCode::NOT_FOUND, true, None));
+    assert!(matches!(
+        client.get("/2025/day/1"),
+        Err(ClientError::Offline { .. })
+    ));
+}
@@ -1,9 +1,14 @@
+pub mod assembunny;
+pub mod duet;
+pub mod elfcode;
+
 use std::{
-    collections::HashMap,
+    collections::VecDeque,
     fmt::{Debug, Display, Formatter, Result},
     rc::Rc,
 };
 
+use custom_error::custom_error;
 use nom::{
     Err::Failure,
     IResult, Parser,
@@ -14,61 +19,131 @@ use nom::{
 
 use crate::nom_util::single_letter;
 
+/// Registers are stored by index, so register `a` is 0, `b` is 1, and so on.
+pub type Register = usize;
+
+/// How many registers every CPU has; enough for any single-letter name.
+pub const REGISTERS: usize = 26;
+
+custom_error! { pub RegisterError
+    NotALetter{name: char} = "There's no register called '{name}'; they're named with letters.",
+}
+
+pub fn register(name: char) -> std::result::Result<Register, RegisterError> {
+    if !name.is_ascii_alphabetic() {
+        return Err(RegisterError::NotALetter { name });
+    }
+    Ok((name.to_ascii_lowercase() as u8 - b'a') as Register)
+}
+
+pub fn register_name(register: Register) -> char {
+    (b'a' + register as u8) as char
+}
+
+pub fn parse_register(i: &str) -> IResult<&str, Register> {
+    let (input, name) = single_letter(i)?;
+    let register = register(name).map_err(|_| Failure(Error::new(i, ErrorKind::Alpha)))?;
+    Ok((input, register))
+}
+
 pub trait Instruction: Display + Debug {
+    /// The instruction's mnemonic, like `mul` or `jnz`.
+    fn name(&self) -> &'static str;
+
     fn execute(&self, cpu: &mut CPU);
+
+    /// The instruction this one turns into when it's hit by assembunny's `tgl`,
+    /// or `None` if it can't be toggled.
+    fn toggle(&self) -> Option<Rc<dyn Instruction>> {
+        None
+    }
 }
 
 pub type InstructionResult<'a> = IResult<&'a str, Rc<dyn Instruction>>;
 pub type InstructionsResult<'a> = IResult<&'a str, Vec<Rc<dyn Instruction>>>;
 
+/// Parses a single instruction. An instruction set is a slice of these, tried in order.
+pub type Builder = fn(s: &str) -> InstructionResult;
+
+/// An instruction argument that's either a register or a literal value.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum Operand {
+    #[display("{}", register_name(*_0))]
+    Register(Register),
+    #[display("{}", _0)]
+    Literal(i64),
+}
+
+impl Operand {
+    pub fn parse(i: &str) -> IResult<&str, Operand> {
+        if let Ok((input, register)) = parse_register(i) {
+            return Ok((input, Operand::Register(register)));
+        }
+        let (input, value) = i64(i)?;
+        Ok((input, Operand::Literal(value)))
+    }
+}
+
 #[derive(Debug, Display)]
-#[display("hlf {}", register)]
+#[display("hlf {}", register_name(*register))]
 pub struct Half {
-    register: char,
+    register: Register,
 }
 impl Half {
     pub fn build(i: &'_ str) -> InstructionResult<'_> {
-        let (input, (_, register)) = (tag("hlf "), single_letter).parse(i)?;
+        let (input, (_, register)) = (tag("hlf "), parse_register).parse(i)?;
         Ok((input, Rc::new(Self { register })))
     }
 }
 impl Instruction for Half {
+    fn name(&self) -> &'static str {
+        "hlf"
+    }
+
     fn execute(&self, cpu: &mut CPU) {
-        *cpu.registers.get_mut(&self.register).unwrap() /= 2;
+        cpu.registers[self.register] /= 2;
     }
 }
 
 #[derive(Debug, Display)]
-#[display("tpl {}", register)]
+#[display("tpl {}", register_name(*register))]
 pub struct Triple {
-    register: char,
+    register: Register,
 }
 impl Triple {
     pub fn build(i: &'_ str) -> InstructionResult<'_> {
-        let (input, (_, register)) = (tag("tpl "), single_letter).parse(i)?;
+        let (input, (_, register)) = (tag("tpl "), parse_register).parse(i)?;
         Ok((input, Rc::new(Self { register })))
     }
 }
 impl Instruction for Triple {
+    fn name(&self) -> &'static str {
+        "tpl"
+    }
+
     fn execute(&self, cpu: &mut CPU) {
-        *cpu.registers.get_mut(&self.register).unwrap() *= 3;
+        cpu.registers[self.register] *= 3;
     }
 }
 
 #[derive(Debug, Display)]
-#[display("inc {}", register)]
+#[display("inc {}", register_name(*register))]
 pub struct Increment {
-    register: char,
+    register: Register,
 }
 impl Increment {
     pub fn build(i: &'_ str) -> InstructionResult<'_> {
-        let (input, (_, register)) = (tag("inc "), single_letter).parse(i)?;
+        let (input, (_, register)) = (tag("inc "), parse_register).parse(i)?;
         Ok((input, Rc::new(Self { register })))
     }
 }
 impl Instruction for Increment {
+    fn name(&self) -> &'static str {
+        "inc"
+    }
+
     fn execute(&self, cpu: &mut CPU) {
-        *cpu.registers.get_mut(&self.register).unwrap() += 1;
+        cpu.registers[self.register] += 1;
     }
 }
 
@@ -84,86 +159,238 @@ impl Jump {
     }
 }
 impl Instruction for Jump {
+    fn name(&self) -> &'static str {
+        "jmp"
+    }
+
     fn execute(&self, cpu: &mut CPU) {
-        cpu.pc += self.offset - 1
+        cpu.jump(self.offset);
     }
 }
 
 #[derive(Debug, Display)]
-#[display("jie {} {}", register, offset)]
+#[display("jie {}, {}", register_name(*register), offset)]
 pub struct JumpEven {
-    register: char,
+    register: Register,
     offset: i64,
 }
 impl JumpEven {
     pub fn build(i: &'_ str) -> InstructionResult<'_> {
         let (input, (_, register, _, offset)) =
-            (tag("jie "), single_letter, tag(", "), i64).parse(i)?;
+            (tag("jie "), parse_register, tag(", "), i64).parse(i)?;
         Ok((input, Rc::new(Self { register, offset })))
     }
 }
 impl Instruction for JumpEven {
+    fn name(&self) -> &'static str {
+        "jie"
+    }
+
     fn execute(&self, cpu: &mut CPU) {
-        cpu.pc += if cpu.registers[&self.register] % 2 == 0 {
-            self.offset - 1
-        } else {
-            0
+        if cpu.registers[self.register] % 2 == 0 {
+            cpu.jump(self.offset);
         }
     }
 }
 
 #[derive(Debug, Display)]
-#[display("jio {} {}", register, offset)]
+#[display("jio {}, {}", register_name(*register), offset)]
 pub struct JumpOne {
-    register: char,
+    register: Register,
     offset: i64,
 }
 impl JumpOne {
     pub fn build(i: &'_ str) -> InstructionResult<'_> {
         let (input, (_, register, _, offset)) =
-            (tag("jio "), single_letter, tag(", "), i64).parse(i)?;
+            (tag("jio "), parse_register, tag(", "), i64).parse(i)?;
         Ok((input, Rc::new(Self { register, offset })))
     }
 }
 impl Instruction for JumpOne {
+    fn name(&self) -> &'static str {
+        "jio"
+    }
+
     fn execute(&self, cpu: &mut CPU) {
-        cpu.pc += if cpu.registers[&self.register] == 1 {
-            self.offset - 1
-        } else {
-            0
+        if cpu.registers[self.register] == 1 {
+            cpu.jump(self.offset);
         }
     }
 }
 
+/// The instructions understood by 2015's Turing lock.
+pub const TURING_LOCK: &[Builder] = &[
+    Half::build,
+    Triple::build,
+    Increment::build,
+    Jump::build,
+    JumpEven::build,
+    JumpOne::build,
+];
+
 #[derive(Clone)]
 pub struct CPU {
-    registers: HashMap<char, i64>,
+    registers: [i64; REGISTERS],
     pc: i64,
+    ip_register: Option<Register>,
     instructions: Vec<Rc<dyn Instruction>>,
+    inbox: VecDeque<i64>,
+    outbox: VecDeque<i64>,
+    sent: usize,
+    waiting: bool,
+    halted: bool,
 }
 
 impl CPU {
-    pub fn new(registers: HashMap<char, i64>, instructions: Vec<Rc<dyn Instruction>>) -> CPU {
+    pub fn new(instructions: Vec<Rc<dyn Instruction>>) -> CPU {
         CPU {
-            registers,
+            registers: [0; REGISTERS],
             pc: 0,
+            ip_register: None,
             instructions,
+            inbox: VecDeque::new(),
+            outbox: VecDeque::new(),
+            sent: 0,
+            waiting: false,
+            halted: false,
+        }
+    }
+
+    /// Mirror the instruction pointer into `register`, like elfcode's `#ip` directive.
+    /// The register holds the pc while each instruction runs, and any change to it is a jump.
+    pub fn set_ip_register(&mut self, register: Register) {
+        self.ip_register = Some(register);
+    }
+
+    /// Run the next instruction, returning `false` if the program has halted,
+    /// or is waiting to receive a value that hasn't arrived yet.
+    pub fn step(&mut self) -> bool {
+        if self.is_halted() || self.is_waiting() {
+            return false;
+        }
+        self.waiting = false;
+        if let Some(register) = self.ip_register {
+            self.registers[register] = self.pc;
+        }
+        let instruction = Rc::clone(&self.instructions[self.pc as usize]);
+        instruction.execute(self);
+        if let Some(register) = self.ip_register {
+            self.pc = self.registers[register];
+        }
+        // A blocked receive tries the same instruction again next time.
+        if !self.waiting {
+            self.pc += 1;
+        }
+        true
+    }
+
+    /// Run until the program halts or blocks, returning the number of instructions executed.
+    pub fn run(&mut self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
         }
+        steps
     }
 
-    pub fn execute(&self) -> Option<Self> {
-        if self.pc < 0 || self.pc >= self.instructions.len() as i64 {
+    /// Like `run`, but give up after `limit` instructions.
+    pub fn run_for(&mut self, limit: usize) -> usize {
+        let mut steps = 0;
+        while steps < limit && self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted || self.pc < 0 || self.pc >= self.instructions.len() as i64
+    }
+
+    /// Whether the program is blocked on a receive with nothing in its inbox.
+    pub fn is_waiting(&self) -> bool {
+        self.waiting && self.inbox.is_empty()
+    }
+
+    pub fn pc(&self) -> i64 {
+        self.pc
+    }
+
+    pub fn registers(&self) -> &[i64; REGISTERS] {
+        &self.registers
+    }
+
+    /// The instruction that will run next, or `None` if the program has halted.
+    pub fn next_instruction(&self) -> Option<&dyn Instruction> {
+        if self.is_halted() {
             return None;
         }
-        let mut rv = self.clone();
-        let instruction = &self.instructions[rv.pc as usize];
-        instruction.execute(&mut rv);
-        rv.pc += 1;
-        Some(rv)
+        Some(self.instructions[self.pc as usize].as_ref())
     }
 
-    pub fn get_register(&self, register: char) -> i64 {
-        self.registers[&register]
+    pub fn get_register(&self, register: Register) -> i64 {
+        self.registers[register]
+    }
+
+    pub fn set_register(&mut self, register: Register, value: i64) {
+        self.registers[register] = value;
+    }
+
+    pub fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.registers[register],
+            Operand::Literal(value) => value,
+        }
+    }
+
+    /// Move the pc by `offset` relative to the current instruction.
+    pub fn jump(&mut self, offset: i64) {
+        self.pc += offset - 1;
+    }
+
+    pub fn halt(&mut self) {
+        self.halted = true;
+    }
+
+    /// Toggle the instruction `offset` away from the current one, if there is one.
+    pub fn toggle(&mut self, offset: i64) {
+        let index = self.pc + offset;
+        if index < 0 || index >= self.instructions.len() as i64 {
+            return;
+        }
+        if let Some(toggled) = self.instructions[index as usize].toggle() {
+            self.instructions[index as usize] = toggled;
+        }
+    }
+
+    pub fn send(&mut self, value: i64) {
+        self.sent += 1;
+        self.outbox.push_back(value);
+    }
+
+    /// Take the next value from the inbox. If it's empty, the current instruction
+    /// will be run again once something arrives.
+    pub fn receive(&mut self) -> Option<i64> {
+        let rv = self.inbox.pop_front();
+        self.waiting = rv.is_none();
+        rv
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.inbox.push_back(value);
+    }
+
+    pub fn take_output(&mut self) -> Vec<i64> {
+        self.outbox.drain(..).collect()
+    }
+
+    /// The most recently sent value that hasn't been taken yet.
+    pub fn last_output(&self) -> Option<i64> {
+        self.outbox.back().copied()
+    }
+
+    /// How many values this program has sent in total.
+    pub fn sent(&self) -> usize {
+        self.sent
     }
 }
 
@@ -182,10 +409,25 @@ impl Display for CPU {
     }
 }
 
-pub fn parse_instructions<'a>(
-    s: &'a str,
-    builders: &[fn(s: &str) -> InstructionResult],
-) -> InstructionsResult<'a> {
+/// Run the programs until they've all halted or are deadlocked,
+/// passing everything each one sends to the next one in the ring.
+pub fn run_ring(cpus: &mut [CPU]) {
+    let count = cpus.len();
+    let mut progress = true;
+    while progress {
+        progress = false;
+        for i in 0..count {
+            if cpus[i].run() > 0 {
+                progress = true;
+            }
+            for value in cpus[i].take_output() {
+                cpus[(i + 1) % count].push_input(value);
+            }
+        }
+    }
+}
+
+pub fn parse_instructions<'a>(s: &'a str, builders: &[Builder]) -> InstructionsResult<'a> {
     let mut instructions: Vec<Rc<dyn Instruction>> = vec![];
     for line in s.lines() {
         let mut found = false;
@@ -204,3 +446,15 @@ pub fn parse_instructions<'a>(
     }
     Ok(("", instructions))
 }
+
+#[test]
+fn test_register() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(register('a').unwrap(), 0);
+    assert_eq!(register('Z').unwrap(), 25);
+    assert!(matches!(
+        register('1'),
+        Err(RegisterError::NotALetter { name: '1' })
+    ));
+}
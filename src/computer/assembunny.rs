@@ -0,0 +1,215 @@
+//! The assembunny dialect from 2016 days 12, 23, and 25.
+
+use std::rc::Rc;
+
+use nom::{Parser, bytes::complete::tag};
+
+use super::{Builder, CPU, Instruction, InstructionResult, Operand};
+
+pub const INSTRUCTIONS: &[Builder] = &[
+    CopyValue::build,
+    Increment::build,
+    Decrement::build,
+    JumpNotZero::build,
+    Toggle::build,
+    Out::build,
+];
+
+/// Apply `f` to `operand` if it's a register. Toggling can produce nonsense like `inc 1`,
+/// which is skipped.
+fn update(cpu: &mut CPU, operand: Operand, f: impl FnOnce(i64) -> i64) {
+    if let Operand::Register(register) = operand {
+        let value = cpu.get_register(register);
+        cpu.set_register(register, f(value));
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("cpy {} {}", source, dest)]
+pub struct CopyValue {
+    source: Operand,
+    dest: Operand,
+}
+impl CopyValue {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, source, _, dest)) =
+            (tag("cpy "), Operand::parse, tag(" "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { source, dest })))
+    }
+}
+impl Instruction for CopyValue {
+    fn name(&self) -> &'static str {
+        "cpy"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        let value = cpu.value(self.source);
+        update(cpu, self.dest, |_| value);
+    }
+
+    fn toggle(&self) -> Option<Rc<dyn Instruction>> {
+        Some(Rc::new(JumpNotZero {
+            test: self.source,
+            offset: self.dest,
+        }))
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("inc {}", register)]
+pub struct Increment {
+    register: Operand,
+}
+impl Increment {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, register)) = (tag("inc "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { register })))
+    }
+}
+impl Instruction for Increment {
+    fn name(&self) -> &'static str {
+        "inc"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        update(cpu, self.register, |value| value + 1);
+    }
+
+    fn toggle(&self) -> Option<Rc<dyn Instruction>> {
+        Some(Rc::new(Decrement {
+            register: self.register,
+        }))
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("dec {}", register)]
+pub struct Decrement {
+    register: Operand,
+}
+impl Decrement {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, register)) = (tag("dec "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { register })))
+    }
+}
+impl Instruction for Decrement {
+    fn name(&self) -> &'static str {
+        "dec"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        update(cpu, self.register, |value| value - 1);
+    }
+
+    fn toggle(&self) -> Option<Rc<dyn Instruction>> {
+        Some(Rc::new(Increment {
+            register: self.register,
+        }))
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("jnz {} {}", test, offset)]
+pub struct JumpNotZero {
+    test: Operand,
+    offset: Operand,
+}
+impl JumpNotZero {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, test, _, offset)) =
+            (tag("jnz "), Operand::parse, tag(" "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { test, offset })))
+    }
+}
+impl Instruction for JumpNotZero {
+    fn name(&self) -> &'static str {
+        "jnz"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        if cpu.value(self.test) != 0 {
+            cpu.jump(cpu.value(self.offset));
+        }
+    }
+
+    fn toggle(&self) -> Option<Rc<dyn Instruction>> {
+        Some(Rc::new(CopyValue {
+            source: self.test,
+            dest: self.offset,
+        }))
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("tgl {}", offset)]
+pub struct Toggle {
+    offset: Operand,
+}
+impl Toggle {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, offset)) = (tag("tgl "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { offset })))
+    }
+}
+impl Instruction for Toggle {
+    fn name(&self) -> &'static str {
+        "tgl"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        cpu.toggle(cpu.value(self.offset));
+    }
+
+    fn toggle(&self) -> Option<Rc<dyn Instruction>> {
+        Some(Rc::new(Increment {
+            register: self.offset,
+        }))
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("out {}", value)]
+pub struct Out {
+    value: Operand,
+}
+impl Out {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, value)) = (tag("out "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { value })))
+    }
+}
+impl Instruction for Out {
+    fn name(&self) -> &'static str {
+        "out"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        cpu.send(cpu.value(self.value));
+    }
+
+    fn toggle(&self) -> Option<Rc<dyn Instruction>> {
+        Some(Rc::new(Increment {
+            register: self.value,
+        }))
+    }
+}
+
+#[test]
+fn test_toggle() {
+    use pretty_assertions::assert_eq;
+
+    use super::{parse_instructions, register};
+
+    let program = "cpy 2 a
+tgl a
+tgl a
+tgl a
+cpy 1 a
+dec a
+dec a";
+    let instructions = parse_instructions(program, INSTRUCTIONS).unwrap().1;
+    let mut cpu = CPU::new(instructions);
+    cpu.run();
+    assert_eq!(cpu.get_register(register('a').unwrap()), 3);
+}
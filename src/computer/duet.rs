@@ -0,0 +1,259 @@
+//! The duet dialect from 2017 days 18 and 23.
+
+use std::rc::Rc;
+
+use nom::{Parser, bytes::complete::tag};
+
+use super::{
+    Builder, CPU, Instruction, InstructionResult, Operand, Register, parse_register, register_name,
+};
+
+/// Day 18 part A, where `snd` plays a sound and `rcv` recovers the last one played.
+pub const SOUND: &[Builder] = &[
+    SendValue::build,
+    Recover::build,
+    set,
+    add,
+    mul,
+    modulo,
+    JumpGreaterThanZero::build,
+];
+
+/// Day 18 part B, where `snd` and `rcv` pass messages between two programs.
+pub const DUET: &[Builder] = &[
+    SendValue::build,
+    ReceiveMessage::build,
+    set,
+    add,
+    mul,
+    modulo,
+    JumpGreaterThanZero::build,
+];
+
+/// Day 23's coprocessor.
+pub const COPROCESSOR: &[Builder] = &[set, sub, mul, JumpNotZero::build];
+
+/// An instruction that stores `op(register, value)` in the register.
+#[derive(Debug, Display)]
+#[display("{} {} {}", name, register_name(*register), value)]
+pub struct Binary {
+    name: &'static str,
+    register: Register,
+    value: Operand,
+    op: fn(i64, i64) -> i64,
+}
+impl Binary {
+    fn parse_with<'a>(
+        i: &'a str,
+        name: &'static str,
+        op: fn(i64, i64) -> i64,
+    ) -> InstructionResult<'a> {
+        let (input, (_, _, register, _, value)) = (
+            tag(name),
+            tag(" "),
+            parse_register,
+            tag(" "),
+            Operand::parse,
+        )
+            .parse(i)?;
+        Ok((
+            input,
+            Rc::new(Self {
+                name,
+                register,
+                value,
+                op,
+            }),
+        ))
+    }
+}
+impl Instruction for Binary {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        let value = (self.op)(cpu.get_register(self.register), cpu.value(self.value));
+        cpu.set_register(self.register, value);
+    }
+}
+
+pub fn set(i: &'_ str) -> InstructionResult<'_> {
+    Binary::parse_with(i, "set", |_, value| value)
+}
+
+pub fn add(i: &'_ str) -> InstructionResult<'_> {
+    Binary::parse_with(i, "add", |register, value| register + value)
+}
+
+pub fn sub(i: &'_ str) -> InstructionResult<'_> {
+    Binary::parse_with(i, "sub", |register, value| register - value)
+}
+
+pub fn mul(i: &'_ str) -> InstructionResult<'_> {
+    Binary::parse_with(i, "mul", |register, value| register * value)
+}
+
+pub fn modulo(i: &'_ str) -> InstructionResult<'_> {
+    Binary::parse_with(i, "mod", |register, value| register % value)
+}
+
+/// Plays a sound in part A, or sends a message to the other program in part B.
+/// Either way, the value ends up in the outbox.
+#[derive(Debug, Display)]
+#[display("snd {}", value)]
+pub struct SendValue {
+    value: Operand,
+}
+impl SendValue {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, value)) = (tag("snd "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { value })))
+    }
+}
+impl Instruction for SendValue {
+    fn name(&self) -> &'static str {
+        "snd"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        cpu.send(cpu.value(self.value));
+    }
+}
+
+/// Halts the program if the value isn't zero, leaving the recovered sound in `last_output`.
+#[derive(Debug, Display)]
+#[display("rcv {}", value)]
+pub struct Recover {
+    value: Operand,
+}
+impl Recover {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, value)) = (tag("rcv "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { value })))
+    }
+}
+impl Instruction for Recover {
+    fn name(&self) -> &'static str {
+        "rcv"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        if cpu.value(self.value) != 0 {
+            cpu.halt();
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("rcv {}", register_name(*register))]
+pub struct ReceiveMessage {
+    register: Register,
+}
+impl ReceiveMessage {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, register)) = (tag("rcv "), parse_register).parse(i)?;
+        Ok((input, Rc::new(Self { register })))
+    }
+}
+impl Instruction for ReceiveMessage {
+    fn name(&self) -> &'static str {
+        "rcv"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        if let Some(value) = cpu.receive() {
+            cpu.set_register(self.register, value);
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("jgz {} {}", test, offset)]
+pub struct JumpGreaterThanZero {
+    test: Operand,
+    offset: Operand,
+}
+impl JumpGreaterThanZero {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, test, _, offset)) =
+            (tag("jgz "), Operand::parse, tag(" "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { test, offset })))
+    }
+}
+impl Instruction for JumpGreaterThanZero {
+    fn name(&self) -> &'static str {
+        "jgz"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        if cpu.value(self.test) > 0 {
+            cpu.jump(cpu.value(self.offset));
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("jnz {} {}", test, offset)]
+pub struct JumpNotZero {
+    test: Operand,
+    offset: Operand,
+}
+impl JumpNotZero {
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (_, test, _, offset)) =
+            (tag("jnz "), Operand::parse, tag(" "), Operand::parse).parse(i)?;
+        Ok((input, Rc::new(Self { test, offset })))
+    }
+}
+impl Instruction for JumpNotZero {
+    fn name(&self) -> &'static str {
+        "jnz"
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        if cpu.value(self.test) != 0 {
+            cpu.jump(cpu.value(self.offset));
+        }
+    }
+}
+
+#[test]
+fn test_duet() {
+    use pretty_assertions::assert_eq;
+
+    use super::{parse_instructions, register, run_ring};
+
+    let program = "set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2";
+    let instructions = parse_instructions(program, SOUND).unwrap().1;
+    let mut cpu = CPU::new(instructions);
+    cpu.run();
+    assert_eq!(cpu.last_output(), Some(4));
+
+    let program = "snd 1
+snd 2
+snd p
+rcv a
+rcv b
+rcv c
+rcv d";
+    let instructions = parse_instructions(program, DUET).unwrap().1;
+    let mut cpus: Vec<CPU> = (0..2)
+        .map(|p| {
+            let mut cpu = CPU::new(instructions.clone());
+            cpu.set_register(register('p').unwrap(), p);
+            cpu
+        })
+        .collect();
+    run_ring(&mut cpus);
+    assert_eq!(cpus[1].sent(), 3);
+}
@@ -0,0 +1,174 @@
+//! The elfcode dialect from 2018 days 16, 19, and 21.
+
+use std::rc::Rc;
+
+use nom::{
+    Err, IResult, Parser,
+    bytes::complete::tag,
+    character::complete::{alpha1, space1, u64},
+    error::{Error, ErrorKind},
+};
+
+use super::{Builder, CPU, Instruction, InstructionResult, Register, parse_instructions};
+
+pub const INSTRUCTIONS: &[Builder] = &[Op::build];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    AddR,
+    AddI,
+    MulR,
+    MulI,
+    BanR,
+    BanI,
+    BorR,
+    BorI,
+    SetR,
+    SetI,
+    GtIR,
+    GtRI,
+    GtRR,
+    EqIR,
+    EqRI,
+    EqRR,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 16] = [
+        Opcode::AddR,
+        Opcode::AddI,
+        Opcode::MulR,
+        Opcode::MulI,
+        Opcode::BanR,
+        Opcode::BanI,
+        Opcode::BorR,
+        Opcode::BorI,
+        Opcode::SetR,
+        Opcode::SetI,
+        Opcode::GtIR,
+        Opcode::GtRI,
+        Opcode::GtRR,
+        Opcode::EqIR,
+        Opcode::EqRI,
+        Opcode::EqRR,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Opcode::AddR => "addr",
+            Opcode::AddI => "addi",
+            Opcode::MulR => "mulr",
+            Opcode::MulI => "muli",
+            Opcode::BanR => "banr",
+            Opcode::BanI => "bani",
+            Opcode::BorR => "borr",
+            Opcode::BorI => "bori",
+            Opcode::SetR => "setr",
+            Opcode::SetI => "seti",
+            Opcode::GtIR => "gtir",
+            Opcode::GtRI => "gtri",
+            Opcode::GtRR => "gtrr",
+            Opcode::EqIR => "eqir",
+            Opcode::EqRI => "eqri",
+            Opcode::EqRR => "eqrr",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Opcode> {
+        Opcode::ALL.into_iter().find(|opcode| opcode.name() == name)
+    }
+
+    /// The value this opcode would store in register C, given inputs A and B.
+    pub fn evaluate(self, cpu: &CPU, a: i64, b: i64) -> i64 {
+        let reg = |n: i64| cpu.get_register(n as Register);
+        match self {
+            Opcode::AddR => reg(a) + reg(b),
+            Opcode::AddI => reg(a) + b,
+            Opcode::MulR => reg(a) * reg(b),
+            Opcode::MulI => reg(a) * b,
+            Opcode::BanR => reg(a) & reg(b),
+            Opcode::BanI => reg(a) & b,
+            Opcode::BorR => reg(a) | reg(b),
+            Opcode::BorI => reg(a) | b,
+            Opcode::SetR => reg(a),
+            Opcode::SetI => a,
+            Opcode::GtIR => (a > reg(b)) as i64,
+            Opcode::GtRI => (reg(a) > b) as i64,
+            Opcode::GtRR => (reg(a) > reg(b)) as i64,
+            Opcode::EqIR => (a == reg(b)) as i64,
+            Opcode::EqRI => (reg(a) == b) as i64,
+            Opcode::EqRR => (reg(a) == reg(b)) as i64,
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+#[display("{} {} {} {}", opcode.name(), a, b, c)]
+pub struct Op {
+    opcode: Opcode,
+    a: i64,
+    b: i64,
+    c: i64,
+}
+impl Op {
+    pub fn new(opcode: Opcode, a: i64, b: i64, c: i64) -> Self {
+        Op { opcode, a, b, c }
+    }
+
+    pub fn build(i: &'_ str) -> InstructionResult<'_> {
+        let (input, (name, _, a, _, b, _, c)) =
+            (alpha1, space1, u64, space1, u64, space1, u64).parse(i)?;
+        let opcode = Opcode::from_name(name).ok_or(Err::Error(Error::new(i, ErrorKind::Tag)))?;
+        Ok((
+            input,
+            Rc::new(Self::new(opcode, a as i64, b as i64, c as i64)),
+        ))
+    }
+}
+impl Instruction for Op {
+    fn name(&self) -> &'static str {
+        self.opcode.name()
+    }
+
+    fn execute(&self, cpu: &mut CPU) {
+        let value = self.opcode.evaluate(cpu, self.a, self.b);
+        cpu.set_register(self.c as Register, value);
+    }
+}
+
+fn ip_directive(i: &str) -> IResult<&str, u64> {
+    let (input, (_, ip)) = (tag("#ip "), u64).parse(i)?;
+    Ok((input, ip))
+}
+
+/// Load a program, binding the instruction pointer if it starts with an `#ip` directive.
+pub fn load(s: &str) -> CPU {
+    let (program, ip) = match ip_directive(s) {
+        Ok((rest, ip)) => (rest.trim_start(), Some(ip)),
+        Err(_) => (s, None),
+    };
+    let instructions = parse_instructions(program, INSTRUCTIONS).unwrap().1;
+    let mut cpu = CPU::new(instructions);
+    if let Some(ip) = ip {
+        cpu.set_ip_register(ip as Register);
+    }
+    cpu
+}
+
+#[test]
+fn test_ip() {
+    use pretty_assertions::assert_eq;
+
+    let mut cpu = load(
+        "#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5",
+    );
+    cpu.run();
+    assert_eq!(cpu.get_register(0), 6);
+}
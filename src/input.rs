@@ -0,0 +1,111 @@
+use std::{
+    fs::read_to_string,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+static INPUT_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Look for `qNN.data` files in `dir` (or `dir/<year>`) instead of next to the question's source.
+pub fn set_data_dir(dir: PathBuf) {
+    DATA_DIR.set(dir).expect("Data directory already set!");
+}
+
+/// Read every question's input from `file`. Only really useful when running a single day.
+pub fn set_input_file(file: PathBuf) {
+    INPUT_FILE.set(file).expect("Input file already set!");
+}
+
+/// Where `year`'s data file called `file_name` lives. That's `src/<year>/data` in the crate,
+/// or the data directory if one was set, preferring its `<year>` subdirectory if that has
+/// the file, or at least exists, so one directory can hold every year's data.
+pub fn data_path(year: &str, file_name: &str) -> PathBuf {
+    match DATA_DIR.get() {
+        Some(dir) => {
+            let per_year = dir.join(year);
+            if per_year.join(file_name).is_file() || per_year.is_dir() {
+                per_year.join(file_name)
+            } else {
+                dir.join(file_name)
+            }
+        }
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join(year)
+            .join("data")
+            .join(file_name),
+    }
+}
+
+/// A question's puzzle input, read from disk the first time it's used.
+#[derive(Debug)]
+pub struct Input {
+    source: &'static str,
+    name: &'static str,
+    contents: OnceLock<String>,
+}
+
+impl Input {
+    pub const fn new(source: &'static str, name: &'static str) -> Self {
+        Input {
+            source,
+            name,
+            contents: OnceLock::new(),
+        }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        if let Some(file) = INPUT_FILE.get() {
+            return file.clone();
+        }
+        let year = Path::new(self.source)
+            .parent()
+            .and_then(Path::file_name)
+            .and_then(|year| year.to_str())
+            .unwrap_or_default();
+        data_path(year, &format!("{}.data", self.name))
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.contents.get_or_init(|| {
+            let path = self.path();
+            read_to_string(&path)
+                .unwrap_or_else(|error| panic!("Couldn't read input {:?}: {}", path, error))
+        })
+    }
+}
+
+/// Lets `q_impl!` hand either a loaded `Input` or a literal `INPUT` to `process_data_a`/`b`.
+pub trait PuzzleInput<T> {
+    fn value(&'static self) -> T;
+}
+
+impl PuzzleInput<&'static str> for Input {
+    fn value(&'static self) -> &'static str {
+        self
+    }
+}
+
+impl<T: Copy> PuzzleInput<T> for T {
+    fn value(&'static self) -> T {
+        *self
+    }
+}
+
+/// Declare a question's `INPUT`, read at runtime from `data/<name>.data`,
+/// or embedded in the binary when the `embedded-input` feature is enabled.
+#[macro_export]
+macro_rules! input {
+    ($name:literal) => {
+        #[cfg(feature = "embedded-input")]
+        static INPUT: &str = include_str!(concat!("data/", $name, ".data"));
+        #[cfg(not(feature = "embedded-input"))]
+        static INPUT: aoc::input::Input = aoc::input::Input::new(file!(), $name);
+    };
+}
@@ -0,0 +1,249 @@
+use std::{
+    fs::{File, read_to_string},
+    io::{BufReader, BufWriter, Stdout, Write, stdin, stdout},
+    path::{Path, PathBuf},
+};
+
+use clap::{Arg, ArgAction, command, value_parser};
+use crossterm::{
+    QueueableCommand,
+    style::{Color, Print, ResetColor, SetForegroundColor},
+};
+use custom_error::custom_error;
+use serde_json::{Value, json};
+
+#[allow(dead_code)]
+#[path = "2019/intcode.rs"]
+mod intcode;
+
+use intcode::{Intcode, IntcodeError, State};
+
+custom_error! { ConsoleError
+    IOError{source: std::io::Error} = "io error",
+    JSON{source: serde_json::error::Error} = "json error",
+    ParseIntError{source: std::num::ParseIntError} = "Couldn't parse int",
+    BadSave{path: String} = "{path} isn't a saved machine",
+}
+
+const HELP: &str = "Anything not starting with ':' or '!' is sent to the machine.
+  :help           Show this message
+  :history        List everything sent so far
+  :undo           Go back to before the last input
+  :save <file>    Save the machine and history as JSON
+  :restore <file> Load a machine and history saved with :save
+  !<n>            Send input <n> from the history again
+  :quit           Exit
+";
+
+/// An interactive session: the machine, what's been sent to it,
+/// and snapshots to go back to.
+struct Console {
+    machine: Intcode,
+    numeric: bool,
+    history: Vec<String>,
+    snapshots: Vec<Intcode>,
+}
+
+impl Console {
+    fn send(&mut self, line: &str) -> Result<(), ConsoleError> {
+        // Parse the whole line first, so a bad number doesn't leave half of it queued.
+        let values = if self.numeric {
+            let values = line.split(',').map(|value| value.trim().parse::<i128>());
+            Some(values.collect::<Result<Vec<_>, _>>()?)
+        } else {
+            None
+        };
+        self.snapshots.push(self.machine.clone());
+        match values {
+            Some(values) => self.machine.inputs.extend(values),
+            None => self.machine.send_line(line),
+        }
+        self.history.push(line.to_owned());
+        Ok(())
+    }
+
+    fn undo(&mut self) -> bool {
+        match self.snapshots.pop() {
+            Some(machine) => {
+                self.machine = machine;
+                self.history.pop();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn save(&self, path: &str) -> Result<(), ConsoleError> {
+        let saved = json!({
+            "machine": self.machine.save(),
+            "history": self.history,
+        });
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, &saved)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn restore(&mut self, path: &str) -> Result<(), ConsoleError> {
+        let saved: Value = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        let bad_save = || ConsoleError::BadSave {
+            path: path.to_owned(),
+        };
+        self.machine = Intcode::restore(&saved["machine"]).ok_or_else(bad_save)?;
+        self.history = saved["history"]
+            .as_array()
+            .ok_or_else(bad_save)?
+            .iter()
+            .filter_map(|line| line.as_str().map(str::to_owned))
+            .collect();
+        // The snapshots from before the save weren't kept.
+        self.snapshots.clear();
+        Ok(())
+    }
+
+    /// Run until the machine wants input, printing everything it says.
+    fn run(&mut self, stdout: &mut Stdout) -> Result<(), ConsoleError> {
+        let result = self.machine.run_tape();
+        stdout.queue(Print(self.machine.read_string()))?;
+        match result {
+            Ok(State::Halted) | Err(IntcodeError::MachineHalted) => {
+                status(stdout, Color::Green, "[halted]")?
+            }
+            Ok(_) => {}
+            Err(error) => status(stdout, Color::Red, &format!("[error: {}]", error))?,
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+fn status(stdout: &mut Stdout, color: Color, message: &str) -> Result<(), ConsoleError> {
+    stdout
+        .queue(SetForegroundColor(color))?
+        .queue(Print(format!("{}\n", message)))?
+        .queue(ResetColor)?;
+    Ok(())
+}
+
+fn read_tape(path: &Path) -> Result<Vec<i128>, ConsoleError> {
+    let tape = read_to_string(path)?
+        .trim()
+        .split(',')
+        .map(|i| i.trim().parse::<i128>())
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(tape)
+}
+
+fn main() -> Result<(), ConsoleError> {
+    color_backtrace::install();
+    let matches = command!("\n")
+        .about("Play an Intcode tape interactively")
+        .arg(
+            Arg::new("tape")
+                .help("The tape to run, as comma-separated numbers")
+                .required_unless_present("restore")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("restore")
+                .short('r')
+                .long("restore")
+                .help("Start from a machine saved with :save")
+                .value_name("file"),
+        )
+        .arg(
+            Arg::new("numeric")
+                .short('n')
+                .long("numeric")
+                .help("Read input as comma-separated numbers, rather than ASCII lines")
+                .action(ArgAction::SetTrue),
+        )
+        .get_matches();
+
+    let mut stdout = stdout();
+    let tape = match matches.get_one::<PathBuf>("tape") {
+        Some(path) => read_tape(path)?,
+        None => vec![],
+    };
+    let mut console = Console {
+        machine: Intcode::new(tape, vec![]),
+        numeric: matches.get_flag("numeric"),
+        history: vec![],
+        snapshots: vec![],
+    };
+    if let Some(path) = matches.get_one::<String>("restore") {
+        console.restore(path)?;
+    }
+
+    console.run(&mut stdout)?;
+    for line in stdin().lines() {
+        let line = line?;
+        let line = line.trim_end();
+        let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+        let result = if let Some(index) = line.strip_prefix('!') {
+            match index
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| console.history.get(i).cloned())
+            {
+                Some(input) => {
+                    stdout.queue(Print(format!("{}\n", input)))?;
+                    console.send(&input).and_then(|_| console.run(&mut stdout))
+                }
+                None => status(&mut stdout, Color::Red, "[no such input]"),
+            }
+        } else {
+            match command {
+                ":help" => {
+                    stdout.queue(Print(HELP))?;
+                    Ok(())
+                }
+                ":history" => {
+                    for (i, input) in console.history.iter().enumerate() {
+                        stdout.queue(Print(format!("{:>4}  {}\n", i, input)))?;
+                    }
+                    Ok(())
+                }
+                ":undo" => {
+                    if console.undo() {
+                        status(&mut stdout, Color::Yellow, "[undone]")
+                    } else {
+                        status(&mut stdout, Color::Red, "[nothing to undo]")
+                    }
+                }
+                ":save" => console
+                    .save(argument)
+                    .and_then(|_| status(&mut stdout, Color::Yellow, "[saved]")),
+                ":restore" => console
+                    .restore(argument)
+                    .and_then(|_| status(&mut stdout, Color::Yellow, "[restored]")),
+                ":quit" => break,
+                _ => console.send(line).and_then(|_| console.run(&mut stdout)),
+            }
+        };
+        if let Err(error) = result {
+            status(&mut stdout, Color::Red, &format!("[{}]", error))?;
+        }
+        stdout.flush()?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_console() {
+    use pretty_assertions::assert_eq;
+
+    let mut console = Console {
+        machine: Intcode::new(vec![99], vec![]),
+        numeric: true,
+        history: vec![],
+        snapshots: vec![],
+    };
+    console.send("1, 2").unwrap();
+    assert!(console.send("3, x").is_err());
+    assert_eq!(console.machine.inputs, vec![1, 2]);
+    assert_eq!(console.history, vec!["1, 2"]);
+    assert!(console.undo());
+    assert!(console.machine.inputs.is_empty());
+    assert!(!console.undo());
+}
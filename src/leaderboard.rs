@@ -0,0 +1,378 @@
+use serde_json::{Map, Value};
+
+use crate::calendar::{awards_points, puzzle_count, unlock_time};
+
+/// When someone got each star of a day, in seconds since the epoch.
+pub type Stars = (Option<i64>, Option<i64>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: String,
+    pub name: String,
+    pub stars: i64,
+    pub local_score: i64,
+    pub global_score: i64,
+    /// One entry per day of the event.
+    pub completions: Vec<Stars>,
+}
+
+impl Member {
+    fn from_data(data: &Map<String, Value>, days: u32) -> Self {
+        let star = |day: &Value, part| day.get(part).and_then(|star| star["get_star_ts"].as_i64());
+        let completions = (1..=days)
+            .map(
+                |day| match data["completion_day_level"].get(day.to_string()) {
+                    Some(completion) => (star(completion, "1"), star(completion, "2")),
+                    None => (None, None),
+                },
+            )
+            .collect();
+
+        Member {
+            id: data["id"].to_string(),
+            name: data["name"]
+                .as_str()
+                .map_or_else(|| format!("Anonymous User ({})", data["id"]), str::to_owned),
+            stars: data["stars"].as_i64().unwrap_or(0),
+            local_score: data["local_score"].as_i64().unwrap_or(0),
+            global_score: data["global_score"].as_i64().unwrap_or(0),
+            completions,
+        }
+    }
+
+    /// When the member got `part` (1 or 2) of `day`.
+    pub fn star(&self, day: u32, part: u32) -> Option<i64> {
+        let stars = self.completions.get((day as usize).checked_sub(1)?)?;
+        if part == 1 { stars.0 } else { stars.1 }
+    }
+
+    /// The longest run of days with both stars in the first `released` days, and the run
+    /// that's still going. Today's puzzle not being done yet doesn't break it.
+    pub fn streaks(&self, released: u32) -> (u32, u32) {
+        let done: Vec<bool> = (1..=released)
+            .map(|day| self.star(day, 2).is_some())
+            .collect();
+        let longest = done
+            .split(|&done| !done)
+            .map(|run| run.len() as u32)
+            .max()
+            .unwrap_or(0);
+        let mut runs = done.rsplit(|&done| !done);
+        let current = match done.last() {
+            Some(false) => runs.nth(1),
+            _ => runs.next(),
+        };
+        (longest, current.map_or(0, |run| run.len() as u32))
+    }
+}
+
+/// A private leaderboard, as returned by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub owner_id: String,
+    pub event: String,
+    pub year: i32,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn from_data(data: &Map<String, Value>) -> Self {
+        let event = data["event"].as_str().unwrap_or_default().to_owned();
+        let year = event.parse().unwrap_or_default();
+        let days = puzzle_count(year);
+        let mut members: Vec<Member> = data["members"]
+            .as_object()
+            .into_iter()
+            .flat_map(|members| members.values())
+            .filter_map(|member| Some(Member::from_data(member.as_object()?, days)))
+            .collect();
+        members.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut board = Leaderboard {
+            owner_id: data["owner_id"].to_string(),
+            event,
+            year,
+            members,
+        };
+        let standings = board.standings();
+        board.members = standings
+            .into_iter()
+            .map(|i| board.members[i].clone())
+            .collect();
+        board
+    }
+
+    pub fn days(&self) -> u32 {
+        puzzle_count(self.year)
+    }
+
+    /// Each member's local score, worked out from when they got their stars.
+    pub fn scores(&self) -> Vec<i64> {
+        let mut rv = vec![0; self.members.len()];
+        for (_, i, points) in self.points() {
+            rv[i] += points;
+        }
+        rv
+    }
+
+    /// The members' indices in leaderboard order: highest score first, and on a tie,
+    /// whoever got to that score first.
+    pub fn standings(&self) -> Vec<usize> {
+        let scores = self.scores();
+        let mut reached = vec![i64::MIN; self.members.len()];
+        for (time, i, points) in self.points() {
+            if points > 0 {
+                reached[i] = time;
+            }
+        }
+        let mut rv: Vec<usize> = (0..self.members.len()).collect();
+        rv.sort_by_key(|&i| (-scores[i], reached[i] == i64::MIN, reached[i]));
+        rv
+    }
+
+    /// The most points each member could end up with: their score so far, plus coming next
+    /// for every star they're still missing, with nobody else getting any more.
+    pub fn max_scores(&self) -> Vec<i64> {
+        let mut rv = self.scores();
+        for day in (1..=self.days()).filter(|&day| awards_points(self.year, day)) {
+            for part in 1..=2 {
+                let have = self
+                    .members
+                    .iter()
+                    .filter(|member| member.star(day, part).is_some())
+                    .count();
+                for (i, member) in self.members.iter().enumerate() {
+                    if member.star(day, part).is_none() {
+                        rv[i] += (self.members.len() - have) as i64;
+                    }
+                }
+            }
+        }
+        rv
+    }
+
+    /// For each member, who's ahead of them that they could still pass. Points never go
+    /// away, so that's anyone whose score now is below their best possible one. Only
+    /// matching it isn't enough, since the tie would go to whoever got there first.
+    pub fn can_overtake(&self) -> Vec<Vec<usize>> {
+        let scores = self.scores();
+        let max_scores = self.max_scores();
+        let mut places = vec![0; self.members.len()];
+        for (place, i) in self.standings().into_iter().enumerate() {
+            places[i] = place;
+        }
+        (0..self.members.len())
+            .map(|i| {
+                let mut ahead: Vec<usize> = (0..self.members.len())
+                    .filter(|&j| places[j] < places[i] && max_scores[i] > scores[j])
+                    .collect();
+                ahead.sort_by_key(|&j| places[j]);
+                ahead
+            })
+            .collect()
+    }
+
+    /// How long after `day` unlocked each member got `part` of it, in seconds.
+    pub fn solve_time(&self, member: &Member, day: u32, part: u32) -> Option<i64> {
+        let unlock = unlock_time(self.year, day)?.timestamp();
+        member.star(day, part).map(|time| time - unlock)
+    }
+
+    /// The first member to get `part` of `day`, and how long it took them.
+    pub fn fastest(&self, day: u32, part: u32) -> Option<(&Member, i64)> {
+        self.members
+            .iter()
+            .filter_map(|member| Some((member, self.solve_time(member, day, part)?)))
+            .min_by_key(|&(_, time)| time)
+    }
+
+    /// Every star anyone has, as (when, member index, local points), in the order they came.
+    /// The first member to get a star scores one point for each member of the board,
+    /// the next one point fewer, and so on, except on days that didn't award any points.
+    pub fn points(&self) -> Vec<(i64, usize, i64)> {
+        let mut rv = vec![];
+        for day in 1..=self.days() {
+            let scored = awards_points(self.year, day);
+            for part in 1..=2 {
+                let mut stars: Vec<(i64, usize)> = self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, member)| Some((member.star(day, part)?, i)))
+                    .collect();
+                stars.sort_unstable();
+                for (rank, (time, i)) in stars.into_iter().enumerate() {
+                    let points = if scored { self.members.len() - rank } else { 0 };
+                    rv.push((time, i, points as i64));
+                }
+            }
+        }
+        rv.sort_unstable();
+        rv
+    }
+
+    /// The stars here that weren't in `before`, an earlier copy of the leaderboard, as
+    /// (when, member index, day, part), in the order they came.
+    pub fn new_stars(&self, before: &Leaderboard) -> Vec<(i64, usize, u32, u32)> {
+        let mut rv = vec![];
+        for (i, member) in self.members.iter().enumerate() {
+            let old = before.members.iter().find(|old| old.id == member.id);
+            for day in 1..=self.days() {
+                for part in 1..=2 {
+                    if let Some(time) = member.star(day, part)
+                        && old.and_then(|old| old.star(day, part)).is_none()
+                    {
+                        rv.push((time, i, day, part));
+                    }
+                }
+            }
+        }
+        rv.sort_unstable();
+        rv
+    }
+
+    /// Each member's local score as it stood when each day's puzzle was replaced by the
+    /// next one, up to the last day anyone has a star for.
+    pub fn history(&self) -> Vec<(u32, Vec<i64>)> {
+        let last_day = (1..=self.days())
+            .filter(|&day| {
+                self.members
+                    .iter()
+                    .any(|member| member.star(day, 1).is_some())
+            })
+            .max()
+            .unwrap_or(0);
+        let points = self.points();
+        (1..=last_day)
+            .filter_map(|day| {
+                let end = unlock_time(self.year, day + 1)?.timestamp();
+                let mut scores = vec![0; self.members.len()];
+                for &(_, i, score) in points.iter().filter(|&&(time, _, _)| time < end) {
+                    scores[i] += score;
+                }
+                Some((day, scores))
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_leaderboard() {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    // Day one unlocked at 1764565200, and day two a day after that.
+    let data = json!({
+        "owner_id": 123,
+        "event": "2025",
+        "members": {
+            "1": {"id": 1, "name": "Ada", "stars": 3, "local_score": 5, "global_score": 0,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1764565260}, "2": {"get_star_ts": 1764565500}},
+                    "2": {"1": {"get_star_ts": 1764652000}}
+                }},
+            "2": {"id": 2, "name": null, "stars": 2, "local_score": 3, "global_score": 0,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1764565230}, "2": {"get_star_ts": 1764651000}}
+                }}
+        }
+    });
+    let board = Leaderboard::from_data(data.as_object().unwrap());
+    assert_eq!(
+        (board.year, board.days(), board.owner_id.as_str()),
+        (2025, 12, "123")
+    );
+    let names: Vec<&str> = board.members.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["Ada", "Anonymous User (2)"]);
+
+    let ada = &board.members[0];
+    assert_eq!(ada.completions.len(), 12);
+    assert_eq!(board.solve_time(ada, 1, 1), Some(60));
+    assert_eq!(board.solve_time(ada, 1, 2), Some(300));
+    assert_eq!(board.solve_time(ada, 2, 2), None);
+    let fastest = |day, part| {
+        board
+            .fastest(day, part)
+            .map(|(m, time)| (m.name.as_str(), time))
+    };
+    assert_eq!(fastest(1, 1), Some(("Anonymous User (2)", 30)));
+    assert_eq!(fastest(1, 2), Some(("Ada", 300)));
+    assert_eq!(fastest(3, 1), None);
+
+    // Nobody has days three to twelve yet, so each of those 20 stars is still worth two points.
+    assert_eq!(board.scores(), vec![5, 3]);
+    assert_eq!(board.max_scores(), vec![5 + 2 + 20 * 2, 3 + 1 + 2 + 20 * 2]);
+    assert_eq!(board.can_overtake(), vec![vec![], vec![0]]);
+
+    assert_eq!(ada.streaks(2), (1, 1));
+    assert_eq!(ada.streaks(3), (1, 0));
+    assert_eq!(board.members[1].streaks(1), (1, 1));
+
+    assert_eq!(board.history(), vec![(1, vec![3, 3]), (2, vec![5, 3])]);
+
+    let mut before = board.clone();
+    before.members[0].completions[1] = (None, None);
+    before.members.pop();
+    assert_eq!(
+        board.new_stars(&before),
+        vec![
+            (1764565230, 1, 1, 1),
+            (1764651000, 1, 1, 2),
+            (1764652000, 0, 2, 1)
+        ]
+    );
+    assert_eq!(board.new_stars(&board), vec![]);
+
+    // Day one of 2020 didn't score, and day two unlocked at 1606885200. Cy and Al both end
+    // up with four points, but Cy got there first.
+    let data = json!({
+        "owner_id": 123,
+        "event": "2020",
+        "members": {
+            "1": {"id": 1, "name": "Al", "stars": 4, "local_score": 4, "global_score": 0,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1606798810}, "2": {"get_star_ts": 1606798820}},
+                    "2": {"1": {"get_star_ts": 1606885220}, "2": {"get_star_ts": 1606885250}}
+                }},
+            "2": {"id": 2, "name": "Bo", "stars": 1, "local_score": 3, "global_score": 0,
+                "completion_day_level": {
+                    "2": {"1": {"get_star_ts": 1606885210}}
+                }},
+            "3": {"id": 3, "name": "Cy", "stars": 2, "local_score": 4, "global_score": 0,
+                "completion_day_level": {
+                    "2": {"1": {"get_star_ts": 1606885230}, "2": {"get_star_ts": 1606885240}}
+                }}
+        }
+    });
+    let board = Leaderboard::from_data(data.as_object().unwrap());
+    let names: Vec<&str> = board.members.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["Cy", "Al", "Bo"]);
+    assert_eq!(board.scores(), vec![4, 4, 3]);
+    assert_eq!(board.standings(), vec![0, 1, 2]);
+    assert_eq!(board.max_scores(), vec![4 + 138, 4 + 138, 3 + 1 + 138]);
+    assert_eq!(board.can_overtake(), vec![vec![], vec![0], vec![0, 1]]);
+
+    // With a day to go, second place is too far behind to catch up.
+    let day = |day: i64, after| Some(1764565200 + (day - 1) * 86400 + after);
+    let member = |id: &str, after| Member {
+        id: id.to_owned(),
+        name: id.to_owned(),
+        stars: 22,
+        local_score: 0,
+        global_score: 0,
+        completions: (1..=12)
+            .map(|d| match d {
+                12 => (None, None),
+                d => (day(d, after), day(d, after + 60)),
+            })
+            .collect(),
+    };
+    let board = Leaderboard {
+        owner_id: String::from("123"),
+        event: String::from("2025"),
+        year: 2025,
+        members: vec![member("X", 10), member("Y", 20)],
+    };
+    assert_eq!(board.scores(), vec![44, 22]);
+    assert_eq!(board.max_scores(), vec![48, 26]);
+    assert!(board.can_overtake().iter().all(Vec::is_empty));
+}
@@ -1,337 +1,269 @@
+use custom_error::custom_error;
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
 
-#[rustfmt::skip]
-//  ██  ███   ██       ████ ████  ██  █  █        ██ █  █ █             ██  ███      ███   ███      █  █                █   █████
-// █  █ █  █ █  █      █    █    █  █ █  █         █ █ █  █            █  █ █  █     █  █ █         █  █                █   █   █
-// █  █ ███  █         ███  ███  █    ████         █ ██   █            █  █ █  █     █  █ █         █  █                 █ █   █ 
-// ████ █  █ █         █    █    █ ██ █  █         █ █ █  █            █  █ ███      ███   ██       █  █                  █   █  
-// █  █ █  █ █  █      █    █    █  █ █  █      █  █ █ █  █            █  █ █        █ █     █      █  █                  █  █   
-// █  █ ███   ██       ████ █     ███ █  █       ██  █  █ ████          ██  █        █  █ ███        ██                   █  ████
-static LETTERS: Lazy<HashMap<Vec<bool>, char>> = Lazy::new(|| {
-    let mut letters = HashMap::new();
-    letters.insert(vec![
-        false, true, true, false, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, true, true, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false],
-        'A');
-    
-    letters.insert(vec![
-        true, true, true, false, false,
-        true, false, false, true, false,
-        true, true, true, false, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, true, true, false, false],
-        'B');
-    
-    letters.insert(vec![
-        false, true, true, false, false,
-        true, false, false, true, false,
-        true, false, false, false, false,
-        true, false, false, false, false,
-        true, false, false, true, false,
-        false, true, true, false, false],
-        'C');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'D');
-    
-    letters.insert(vec![
-        true, true, true, true, false,
-        true, false, false, false, false,
-        true, true, true, false, false,
-        true, false, false, false, false,
-        true, false, false, false, false,
-        true, true, true, true, false],
-        'E');
-    
-    letters.insert(vec![
-        true, true, true, true, false,
-        true, false, false, false, false,
-        true, true, true, false, false,
-        true, false, false, false, false,
-        true, false, false, false, false,
-        true, false, false, false, false],
-        'F');
-    
-    letters.insert(vec![
-        false, true, true, false, false,
-        true, false, false, true, false,
-        true, false, false, false, false,
-        true, false, true, true, false,
-        true, false, false, true, false,
-        false, true, true, true, false],
-        'G');
-    
-    letters.insert(vec![
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, true, true, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false],
-        'H');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'I');
-    
-    letters.insert(vec![
-        false, false, true, true, false,
-        false, false, false, true, false,
-        false, false, false, true, false,
-        false, false, false, true, false,
-        true, false, false, true, false,
-        false, true, true, false, false],
-        'J');
-    
-    letters.insert(vec![
-        true, false, false, true, false,
-        true, false, true, false, false,
-        true, true, false, false, false,
-        true, false, true, false, false,
-        true, false, true, false, false,
-        true, false, false, true, false],
-        'K');
-    
-    letters.insert(vec![
-        true, false, false, false, false,
-        true, false, false, false, false,
-        true, false, false, false, false,
-        true, false, false, false, false,
-        true, false, false, false, false,
-        true, true, true, true, false],
-        'L');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'M');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'N');
-    
-    letters.insert(vec![
-        false, true, true, false, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        false, true, true, false, false],
-        'O');
-    
-    letters.insert(vec![
-        true, true, true, false, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, true, true, false, false,
-        true, false, false, false, false,
-        true, false, false, false, false],
-        'P');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'Q');
-    
-    letters.insert(vec![
-        true, true, true, false, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, true, true, false, false,
-        true, false, true, false, false,
-        true, false, false, true, false],
-        'R');
-    
-    letters.insert(vec![
-        false, true, true, true, false,
-        true, false, false, false, false,
-        true, false, false, false, false,
-        false, true, true, false, false,
-        false, false, false, true, false,
-        true, true, true, false, false],
-        'S');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'T');
-    
-    letters.insert(vec![
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        true, false, false, true, false,
-        false, true, true, false, false],
-        'U');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'V');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'W');
-    
-    letters.insert(vec![
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false,
-        false, false, false, false, false],
-        'X');
-    
-    letters.insert(vec![
-        true, false, false, false, true,
-        true, false, false, false, true,
-        false, true, false, true, false,
-        false, false, true, false, false,
-        false, false, true, false, false,
-        false, false, true, false, false],
-        'Y');
-    
-    letters.insert(vec![
-        true, true, true, true, false,
-        false, false, false, true, false,
-        false, false, true, false, false,
-        false, true, false, false, false,
-        true, false, false, false, false,
-        true, true, true, true, false],
-        'Z');
-    letters
-});
+/// A letter we couldn't read: which one it was in the line, and what it looked like.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    pub index: usize,
+    pub picture: String,
+}
 
-pub fn recognize_letters(image: &[bool]) -> String {
-    let mut image = image.to_vec();
-    let column_count = image.len() / 6;
+/// The letters in an image that we couldn't read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownGlyphs(pub Vec<UnknownGlyph>);
 
-    let mut offset = 0;
-    'outer: loop {
-        for row in 0..6 {
-            if image[row * column_count + offset] {
-                break 'outer;
-            }
+impl Display for UnknownGlyphs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for glyph in &self.0 {
+            write!(
+                f,
+                "Letter {} isn't one we know:\n{}",
+                glyph.index + 1,
+                glyph.picture
+            )?;
         }
-        offset += 1;
+        Ok(())
     }
+}
+
+custom_error! { pub LettersError
+    Empty = "There aren't any letters in the image.",
+    BadWidth{len: usize, width: usize} = "An image of {len} pixels can't be {width} pixels wide.",
+    UnknownFont{height: usize, picture: String} = "There's no font {height} rows high to read these letters with:\n{picture}",
+    Unrecognized{text: String, glyphs: UnknownGlyphs, picture: String} = "Couldn't read all of \"{text}\":\n{picture}{glyphs}",
+}
+
+/// The letters Advent of Code draws, all the same height, each in a cell of the same width.
+struct Font {
+    height: usize,
+    cell: usize,
+    glyphs: HashMap<Vec<bool>, char>,
+}
 
-    // Remove the front offset rows…
-    for row in 0..6 {
-        let last = 5 - row;
-        for _ in 0..offset {
-            let prev = image.remove(last * column_count);
-            assert!(!prev);
+impl Font {
+    /// Read a font from its picture: one row of letters, drawn with `#`, a cell apart.
+    fn new(cell: usize, letters: &str, rows: &[&str]) -> Self {
+        let glyphs = letters
+            .chars()
+            .enumerate()
+            .map(|(i, letter)| {
+                let glyph = rows
+                    .iter()
+                    .flat_map(|row| row[i * cell..(i + 1) * cell].chars().map(|c| c == '#'))
+                    .collect();
+                (glyph, letter)
+            })
+            .collect();
+        Font {
+            height: rows.len(),
+            cell,
+            glyphs,
         }
     }
+}
 
-    let column_count = image.len() / 6;
-    let leftovers = column_count % 5;
+// The small font, used most years.
+#[rustfmt::skip]
+static SMALL: Lazy<Font> = Lazy::new(|| Font::new(5, "ABCDEFGHIJKLOPRSUYZ", &[
+    ".##..###...##..###..####.####..##..#..#.###....##.#..#.#.....##..###..###...###.#..#.#...#####.",
+    "#..#.#..#.#..#.#..#.#....#....#..#.#..#..#......#.#.#..#....#..#.#..#.#..#.#....#..#.#...#...#.",
+    "#..#.###..#....#..#.###..###..#....####..#......#.##...#....#..#.#..#.#..#.#....#..#..#.#...#..",
+    "####.#..#.#....#..#.#....#....#.##.#..#..#......#.#.#..#....#..#.###..###...##..#..#...#...#...",
+    "#..#.#..#.#..#.#..#.#....#....#..#.#..#..#...#..#.#.#..#....#..#.#....#.#.....#.#..#...#..#....",
+    "#..#.###...##..###..####.#.....###.#..#.###...##..#..#.####..##..#....#..#.###...##....#..####.",
+]));
 
-    if leftovers != 0 {
-        // Check to see if the final column_count % 5 rows are blank.
-        let mut blank = true;
-        'outer: for row in 0..6 {
-            for i in 1..=leftovers {
-                let prev = image[row * column_count + column_count - i];
-                if prev {
-                    blank = false;
-                    break 'outer;
-                }
-            }
-        }
+// The large font, from 2018's day ten.
+#[rustfmt::skip]
+static LARGE: Lazy<Font> = Lazy::new(|| Font::new(8, "ABCEFGHJKLNPRXZ", &[
+    "..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######..",
+    ".#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#..",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#..",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#...",
+    "#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#....",
+    "######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#.....",
+    "#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#......",
+    "#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.......",
+    "#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.......",
+    "#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######..",
+]));
 
-        if blank {
-            // If they are, remove them.
-            for row in 0..6 {
-                let last = 5 - row;
-                for i in 1..=leftovers {
-                    let prev = image.remove(last * column_count + column_count - i);
-                    assert!(!prev);
-                }
-            }
-        } else {
-            // Otherwise, add enough blank rows to get to 5.
-            for row in 0..6 {
-                let last = 5 - row;
-                for _ in 0..5 - leftovers {
-                    image.insert((last + 1) * column_count, false);
-                }
-            }
-        }
+/// Draw an image `width` pixels wide, a row per line.
+pub fn draw_image(image: &[bool], width: usize) -> String {
+    draw(&image.chunks(width.max(1)).collect::<Vec<_>>())
+}
+
+fn draw(rows: &[&[bool]]) -> String {
+    rows.iter()
+        .map(|row| {
+            let line: String = row.iter().map(|&on| if on { '█' } else { ' ' }).collect();
+            line + "\n"
+        })
+        .collect()
+}
+
+/// Read the letters in an image `width` pixels wide, picking the font by how tall they are.
+/// Blank rows and columns around them don't matter.
+pub fn recognize_letters(image: &[bool], width: usize) -> Result<String, LettersError> {
+    if width == 0 || !image.len().is_multiple_of(width) {
+        return Err(LettersError::BadWidth {
+            len: image.len(),
+            width,
+        });
     }
-    let column_count = image.len() / 6;
+    let rows: Vec<&[bool]> = image.chunks(width).collect();
+    let top = rows.iter().position(|row| row.contains(&true));
+    let bottom = rows.iter().rposition(|row| row.contains(&true));
+    let (Some(top), Some(bottom)) = (top, bottom) else {
+        return Err(LettersError::Empty);
+    };
+    let rows = &rows[top..=bottom];
+    let blank = |column: usize| rows.iter().all(|row| !row[column]);
+    let left = (0..width).find(|&column| !blank(column)).unwrap_or(0);
+    let right = (0..width).rfind(|&column| !blank(column)).unwrap_or(0) + 1;
+    let picture = draw(&rows.iter().map(|row| &row[left..right]).collect::<Vec<_>>());
+
+    let Some(font) = [&*SMALL, &*LARGE]
+        .into_iter()
+        .find(|font| font.height == rows.len())
+    else {
+        return Err(LettersError::UnknownFont {
+            height: rows.len(),
+            picture,
+        });
+    };
+
+    // Some letters (like the large J) start with blank columns, so the first lit column
+    // isn't always the start of a cell. Try each alignment, and keep the one that reads best.
+    let (text, glyphs) = (0..font.cell.min(left + 1))
+        .map(|shift| read_glyphs(font, rows, left - shift, right))
+        .min_by_key(|(_, glyphs)| glyphs.len())
+        .unwrap();
+    if glyphs.is_empty() {
+        Ok(text)
+    } else {
+        Err(LettersError::Unrecognized {
+            text,
+            glyphs: UnknownGlyphs(glyphs),
+            picture,
+        })
+    }
+}
 
-    let mut picture = String::new();
-    for (index, &value) in image.iter().enumerate() {
-        picture.push(if value { '█' } else { ' ' });
-        if (index + 1) % column_count == 0 {
-            // picture.push('.');
-            picture.push('\n');
+/// Read the letters in a puzzle's answer, panicking with a picture of any we can't read,
+/// since there's no answer to give without them.
+pub fn read_letters(image: &[bool], width: usize) -> String {
+    recognize_letters(image, width)
+        .unwrap_or_else(|error| panic!("Couldn't read the letters. {}", error))
+}
+
+/// Read the cells of `font` from column `left` up to `right`, with a `?` for each one
+/// that isn't a letter. The last letter can be narrower than its cell, so it's padded out.
+fn read_glyphs(
+    font: &Font,
+    rows: &[&[bool]],
+    left: usize,
+    right: usize,
+) -> (String, Vec<UnknownGlyph>) {
+    let mut text = String::new();
+    let mut unknown = vec![];
+    for index in 0..(right - left).div_ceil(font.cell) {
+        let columns = left + index * font.cell..left + (index + 1) * font.cell;
+        let glyph: Vec<bool> = rows
+            .iter()
+            .flat_map(|row| columns.clone().map(|column| row.get(column) == Some(&true)))
+            .collect();
+        match font.glyphs.get(&glyph) {
+            Some(&letter) => text.push(letter),
+            None => {
+                text.push('?');
+                unknown.push(UnknownGlyph {
+                    index,
+                    picture: draw(&glyph.chunks(font.cell).collect::<Vec<_>>()),
+                });
+            }
         }
     }
-    // println!("\n{}", picture);
+    (text, unknown)
+}
 
-    // collect the characters…
-    let char_count = column_count / 5;
-    let mut rv = vec![];
-    for i in 0..char_count {
-        let mut letter = vec![];
-        for row in 0..6 {
-            for column in 0..5 {
-                letter.push(image[row * column_count + column + 5 * i]);
+#[test]
+fn test_letters() {
+    use pretty_assertions::assert_eq;
+
+    // Draw `text` with the letters in `rows`, with a blank border, the way the puzzles do.
+    let render = |rows: &[&str], cell: usize, letters: &str, text: &str| {
+        let width = text.len() * cell + 2;
+        let mut image = vec![false; width];
+        for row in rows {
+            image.push(false);
+            for letter in text.chars() {
+                let i = letters.find(letter).unwrap();
+                image.extend(row[i * cell..(i + 1) * cell].chars().map(|c| c == '#'));
             }
+            image.push(false);
         }
-        if LETTERS.get(&letter).is_none() {
-            // We didn't find one of the letters, so return the picture.
-            // println!("Couldn't find the next letter after {:?}", rv);
-            return picture;
+        image.extend(vec![false; width]);
+        (image, width)
+    };
+
+    // The last letter is a made-up one, which the small font doesn't have.
+    let small = [
+        ".##..####.###..###..#.#..",
+        "#..#.#.....#...#..#..#.#.",
+        "#....###...#...#..#.#.#..",
+        "#.##.#.....#...#..#..#.#.",
+        "#..#.#.....#...#..#.#.#..",
+        ".###.#....###..###...#.#.",
+    ];
+    let (image, width) = render(&small, 5, "GFID?", "FIDG");
+    assert_eq!(recognize_letters(&image, width).unwrap(), "FIDG");
+    let (image, width) = render(&small, 5, "GFID?", "FI?G");
+    match recognize_letters(&image, width) {
+        Err(LettersError::Unrecognized { text, glyphs, .. }) => {
+            assert_eq!(text, "FI?G");
+            assert_eq!(
+                glyphs.0,
+                vec![UnknownGlyph {
+                    index: 2,
+                    picture: String::from("█ █  \n █ █ \n█ █  \n █ █ \n█ █  \n █ █ \n"),
+                }]
+            );
         }
-        rv.push(LETTERS[&letter]);
+        result => panic!("expected an unrecognized letter, not {:?}", result),
     }
-    rv.into_iter().collect()
+
+    let large = [
+        "#....#..######.....###..",
+        "#....#.......#......#...",
+        "#....#.......#......#...",
+        "#....#......#.......#...",
+        "######.....#........#...",
+        "#....#....#.........#...",
+        "#....#...#..........#...",
+        "#....#..#.......#...#...",
+        "#....#..#.......#...#...",
+        "#....#..######...###....",
+    ];
+    let (image, width) = render(&large, 8, "HZJ", "HZZH");
+    assert_eq!(recognize_letters(&image, width).unwrap(), "HZZH");
+    let (image, width) = render(&large, 8, "HZJ", "JH");
+    assert_eq!(recognize_letters(&image, width).unwrap(), "JH");
+
+    assert!(matches!(
+        recognize_letters(&[false; 12], 4),
+        Err(LettersError::Empty)
+    ));
+    assert!(matches!(
+        recognize_letters(&[true; 12], 5),
+        Err(LettersError::BadWidth { .. })
+    ));
+    assert!(matches!(
+        recognize_letters(&[true; 12], 4),
+        Err(LettersError::UnknownFont { height: 3, .. })
+    ));
 }
@@ -1,6 +1,16 @@
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod client;
 pub mod computer;
+pub mod input;
+pub mod leaderboard;
 pub mod letters;
 pub mod nom_util;
+pub mod puzzle;
+pub mod search;
+pub mod session;
+pub mod submission;
 pub mod util;
 
 #[macro_use]
@@ -8,14 +18,137 @@ extern crate clap;
 #[macro_use]
 extern crate derive_more;
 
-use std::ops::Deref;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Display, Formatter, Result},
+    ops::Deref,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
-use clap::Arg;
+use clap::{Arg, ArgAction};
+
+use crate::{
+    answers::{Answers, Verdict},
+    bench::{Benchmark, write_report},
+};
+
+/// The answer to one part of a day's puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// A multi-line answer, like a picture.
+    Letters(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => f.write_str(value),
+            Answer::Letters(picture) => write!(f, "\n{}", picture.trim_end()),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i128::try_from(value) {
+                        Ok(value) => Answer::Number(value),
+                        Err(_) => Answer::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.contains('\n') {
+            Answer::Letters(value)
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_owned())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Part::A => f.write_str("A"),
+            Part::B => f.write_str("B"),
+        }
+    }
+}
+
+/// The result of running one part of a day, along with how long it took.
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub day: String,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{}{}: Result = {} ({:?})",
+            self.day, self.part, self.answer, self.elapsed
+        )
+    }
+}
 
 pub trait Day {
     fn number(&self) -> String;
-    fn a(&self);
-    fn b(&self);
+    fn a(&self) -> Answer;
+    fn b(&self) -> Answer;
+
+    fn run(&self, part: Part) -> Solution {
+        let start = Instant::now();
+        let answer = match part {
+            Part::A => self.a(),
+            Part::B => self.b(),
+        };
+        Solution {
+            day: self.number(),
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        }
+    }
 }
 
 #[macro_export]
@@ -49,7 +182,7 @@ macro_rules! regex {
 #[macro_export]
 macro_rules! q_impl {
     ($e:expr_2021) => {
-        use aoc::Day;
+        use aoc::{Answer, Day, input::PuzzleInput};
 
         pub struct Q;
 
@@ -58,39 +191,115 @@ macro_rules! q_impl {
                 String::from($e)
             }
 
-            fn a(&self) {
-                print!("{}A: ", self.number());
-                let result = process_data_a(INPUT);
-                println!("Result = {}", result);
+            fn a(&self) -> Answer {
+                Answer::from(process_data_a(INPUT.value()))
             }
 
-            fn b(&self) {
-                print!("{}B: ", self.number());
-                let result = process_data_b(INPUT);
-                println!("Result = {}", result);
+            fn b(&self) -> Answer {
+                Answer::from(process_data_b(INPUT.value()))
             }
         }
     };
 }
 
-fn select(day: &dyn Day, arg: &str) {
+/// Load a year's recorded answers, or stop and say what's wrong with the file.
+fn load_answers(year: u32) -> Answers {
+    Answers::load(year).unwrap_or_else(|error| {
+        eprintln!("Couldn't read {:?}: {}", Answers::path(year), error);
+        std::process::exit(1);
+    })
+}
+
+fn select(day: &dyn Day, arg: &str) -> Vec<Part> {
     let day_num = day.number();
     match arg.to_lowercase().replace("::", "") {
-        ref q if *q == format!("{}{}", day_num, "a") => day.a(),
-        ref q if *q == format!("{}{}", day_num, "b") => day.b(),
-        ref q if *q == day.number() => {
-            day.a();
-            day.b()
+        ref q if *q == format!("{}{}", day_num, "a") => vec![Part::A],
+        ref q if *q == format!("{}{}", day_num, "b") => vec![Part::B],
+        ref q if *q == day.number() => vec![Part::A, Part::B],
+        ref q if *q == "*" => vec![Part::A, Part::B],
+        _ => vec![],
+    }
+}
+
+/// Split a selector like `2019/13b` into its year and day parts.
+/// A bare day (`13b`) matches every year, and a bare year (`2019`) matches all of its days.
+fn split_selector(arg: &str) -> (&str, &str) {
+    match arg.split_once('/') {
+        Some((year, day)) => (year, day),
+        None if arg.len() == 4 && arg.parse::<u32>().is_ok() => (arg, "*"),
+        None => ("*", arg),
+    }
+}
+
+/// Run every part of `days` matched by `arg`, returning the answers in order.
+pub fn solve(days: &[Box<dyn Day>], arg: &str) -> Vec<Solution> {
+    let mut rv = vec![];
+    for day in days {
+        for part in select(day.deref(), arg) {
+            rv.push(day.run(part));
+        }
+    }
+    rv
+}
+
+/// Find every part of every year matched by `arg`.
+fn select_years<'a>(
+    years: &[(u32, &'a [Box<dyn Day>])],
+    arg: &str,
+) -> Vec<(u32, &'a dyn Day, Part)> {
+    let (year_arg, day_arg) = split_selector(arg);
+    let mut rv = vec![];
+    for &(year, days) in years {
+        if year_arg != "*" && year_arg != year.to_string() {
+            continue;
         }
-        ref q if *q == "*" => {
-            day.a();
-            day.b()
+        for day in days {
+            for part in select(day.deref(), day_arg) {
+                rv.push((year, day.deref(), part));
+            }
         }
-        _ => (),
     }
+    rv
+}
+
+fn bench(selected: &[(u32, &dyn Day, Part)], warmup: usize, runs: usize, report: Option<&PathBuf>) {
+    let mut benchmarks = vec![];
+    for &(year, day, part) in selected {
+        let benchmark = Benchmark::run(year, day, part, warmup, runs);
+        println!("{}", benchmark);
+        benchmarks.push(benchmark);
+    }
+    let report = report.cloned().unwrap_or_else(|| {
+        let years: BTreeSet<u32> = benchmarks.iter().map(|b| b.year).collect();
+        match years.into_iter().collect::<Vec<_>>()[..] {
+            [year] => PathBuf::from(format!("timings.{}.json", year)),
+            _ => PathBuf::from("timings.json"),
+        }
+    });
+    match write_report(&report, &benchmarks) {
+        Ok(()) => println!("\nWrote report to {:?}", report),
+        Err(error) => {
+            eprintln!("Couldn't write {:?}: {}", report, error);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Run the days for a single year.
+pub fn main(year: u32, days: &[Box<dyn Day>]) {
+    run(&[(year, days)]);
+}
+
+/// Run the days for several years, selecting them with `year/day` arguments.
+pub fn main_years(years: &[(u32, Vec<Box<dyn Day>>)]) {
+    let years: Vec<(u32, &[Box<dyn Day>])> = years
+        .iter()
+        .map(|(year, days)| (*year, days.as_slice()))
+        .collect();
+    run(&years);
 }
 
-pub fn main(days: &[Box<dyn Day>]) {
+fn run(years: &[(u32, &[Box<dyn Day>])]) {
     color_backtrace::install();
     let matches = command!("\n")
         .arg(
@@ -101,20 +310,160 @@ pub fn main(days: &[Box<dyn Day>]) {
  Putting a number and an 'a' or 'b' will run that part for that day.
  Putting a number will run both parts for that day.
  Putting '*' (the default) will run all parts for all days.
+ Prefixing any of those with a year and a '/' (like '2019/13b' or '2016/*')
+ will only run them for that year.
 ",
                 )
                 .index(1)
                 .num_args(0..)
                 .default_value("*"),
         )
+        .arg(
+            Arg::new("input")
+                .short('i')
+                .long("input")
+                .help("Read the puzzle input from this file")
+                .long_help(
+                    "Read the puzzle input from this file instead of data/qNN.data.
+ Best used with a single day, and ignored when built with the embedded-input feature.",
+                )
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("data_dir")
+                .short('d')
+                .long("data-dir")
+                .help("Read the puzzle inputs from this directory")
+                .long_help(
+                    "Read the qNN.data puzzle inputs from this directory
+ (or its <year> subdirectories) instead of src/<year>/data.",
+                )
+                .value_parser(value_parser!(PathBuf))
+                .conflicts_with("input"),
+        )
+        .arg(
+            Arg::new("check")
+                .short('c')
+                .long("check")
+                .help("Check the answers")
+                .long_help("Compare each answer against the ones recorded in data/answers.json.")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("record"),
+        )
+        .arg(
+            Arg::new("record")
+                .short('r')
+                .long("record")
+                .help("Record the answers")
+                .long_help("Save each answer to data/answers.json as the expected answer.")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("bench")
+                .short('b')
+                .long("bench")
+                .help("Benchmark the days")
+                .long_help(
+                    "Time each part in-process, printing the mean and standard deviation,
+ and writing a JSON report that can be diffed between commits.",
+                )
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["check", "record"]),
+        )
+        .arg(
+            Arg::new("warmup")
+                .long("warmup")
+                .help("Number of untimed runs before benchmarking")
+                .value_parser(value_parser!(usize))
+                .default_value("3")
+                .requires("bench"),
+        )
+        .arg(
+            Arg::new("runs")
+                .long("runs")
+                .help("Number of timed runs when benchmarking")
+                .value_parser(value_parser!(usize))
+                .default_value("10")
+                .requires("bench"),
+        )
+        .arg(
+            Arg::new("report")
+                .long("report")
+                .help("Where to write the benchmark report")
+                .long_help(
+                    "Where to write the benchmark report.
+ Defaults to timings.<year>.json, or timings.json when benchmarking several years.",
+                )
+                .value_parser(value_parser!(PathBuf))
+                .requires("bench"),
+        )
         .get_matches();
 
     let args: Vec<&String> = matches.get_many("day").unwrap().collect();
+    if let Some(file) = matches.get_one::<PathBuf>("input") {
+        input::set_input_file(file.clone());
+    }
+    if let Some(dir) = matches.get_one::<PathBuf>("data_dir") {
+        input::set_data_dir(dir.clone());
+    }
+
+    if matches.get_flag("bench") {
+        let warmup = *matches.get_one::<usize>("warmup").unwrap();
+        let runs = *matches.get_one::<usize>("runs").unwrap();
+        let selected: Vec<_> = args
+            .iter()
+            .flat_map(|argument| select_years(years, argument))
+            .collect();
+        bench(
+            &selected,
+            warmup,
+            runs,
+            matches.get_one::<PathBuf>("report"),
+        );
+        return;
+    }
+
+    let check = matches.get_flag("check");
+    let record = matches.get_flag("record");
+
+    let mut answers: BTreeMap<u32, Answers> = BTreeMap::new();
+    let mut failed = false;
 
     for argument in args {
-        for day in days {
-            select(day.deref(), argument);
+        let mut last_year = None;
+        for (year, day, part) in select_years(years, argument) {
+            if years.len() > 1 && last_year != Some(year) {
+                println!("{}:", year);
+                last_year = Some(year);
+            }
+            let solution = day.run(part);
+            if check || record {
+                let answers = answers.entry(year).or_insert_with(|| load_answers(year));
+                if check {
+                    let verdict = answers.check(&solution);
+                    failed |= matches!(verdict, Verdict::Fail { .. });
+                    println!("{} {}", solution, verdict);
+                    continue;
+                }
+                answers.record(&solution);
+            }
+            println!("{}", solution);
         }
         println!();
     }
+
+    if record {
+        for (year, answers) in answers {
+            match answers.save() {
+                Ok(()) => println!("Recorded answers in {:?}", Answers::path(year)),
+                Err(error) => {
+                    eprintln!("Couldn't write {:?}: {}", Answers::path(year), error);
+                    failed = true;
+                }
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
 }
@@ -1,34 +1,42 @@
 use std::{
-    env::{VarError, var},
     fs::{self, File, OpenOptions, copy, create_dir_all, read, read_dir, remove_file},
     io::{Stdout, Write, stdout},
     time::SystemTimeError,
 };
 
-use chrono::{Datelike, Duration, Local};
-use clap::{Arg, ArgAction, command};
+use aoc::{
+    Part,
+    answers::{Answers, AnswersError},
+    calendar::{self, FIRST_YEAR},
+    client::{Client, ClientError},
+    puzzle::{fill_examples, parse_puzzle, to_markdown},
+    session::{Accounts, ENV_ACCOUNT, SessionError},
+    submission::{Attempts, Outcome, SubmissionError, parse_response},
+};
+use chrono::{Datelike, Duration, Local, Utc};
+use clap::{Arg, ArgAction, command, value_parser};
 use crossterm::{ExecutableCommand, style::Print};
 use custom_error::custom_error;
 use humantime::format_duration;
-use reqwest::{
-    blocking::{Client, Response},
-    header::COOKIE,
-};
+use reqwest::StatusCode;
 
 custom_error! { NextError
     IOError{source: std::io::Error} = "io error",
-    Request{source: reqwest::Error} = "request error",
+    Client{source: ClientError} = "{source}",
+    Session{source: SessionError} = "{source}",
     Time{source: SystemTimeError} = "time error",
     JSON{source: serde_json::error::Error} = "json error",
     YearsNotFound{} = "Years not found in directories.",
-    YearNotFound{year:i32} = "Year {year} not found on server.",
-    InputNotFound{year:i32, day:u32} = "Input \"https://adventofcode.com/{year}/day/{day}/input\" not found on server.",
+    NoMorePuzzles{year:i32} = "There are no more puzzles in {year}.",
+    PuzzleNotFound{year:u32, day:u32} = "{year} doesn't have a puzzle on day {day}.",
     ParseIntError{source: std::num::ParseIntError} = "Couldn't parse int",
     // CrossTerm{source: crossterm::Error} = "crossterm error",
     FromUtf8Error{source: std::string::FromUtf8Error} = "Couldn't read input",
     ModulesNotFound{location: String} = "Modules {location} not found in main",
-    Session{source: VarError} = "Could not find AOC_SESSION env variable.",
     DurationError{duration: Duration} = "Sleep Duration {duration} out of range.",
+    BadPuzzle{puzzle: String} = "Couldn't understand {puzzle}, expected something like 2025/12a.",
+    Answers{source: AnswersError} = "answers error",
+    Submission{source: SubmissionError} = "submission error",
 }
 
 fn get_last() -> Result<(u32, String), NextError> {
@@ -66,8 +74,13 @@ fn get_last() -> Result<(u32, String), NextError> {
 }
 
 fn add_day(last_year: u32, day: u32, stdout: &mut Stdout) -> Result<(), NextError> {
+    if !calendar::has_puzzle(last_year as i32, day) {
+        return Err(NextError::PuzzleNotFound {
+            year: last_year,
+            day,
+        });
+    }
     stdout.execute(Print(format!("Adding Day: {}/{:02}\n", last_year, day)))?;
-    File::create(format!("src/{}/data/q{:02}.data", last_year, day))?;
 
     // Copy the template, replacing "XX" with "<:02=day>", and "X" with "<day>".
     let template = String::from_utf8(read("template/qXX.rs")?)?;
@@ -77,10 +90,10 @@ fn add_day(last_year: u32, day: u32, stdout: &mut Stdout) -> Result<(), NextErro
     let mut question = File::create(format!("src/{}/q{:02}.rs", last_year, day))?;
     question.write_all(template.as_bytes())?;
 
-    // Edit main to add "mod q<:02=day>;" and ", q<:02=day>"…
-    stdout.execute(Print(format!("Editing: {}/main.rs\n", last_year)))?;
+    // Edit the year's module to add "mod q<:02=day>;" and ", q<:02=day>"…
+    stdout.execute(Print(format!("Editing: {}/mod.rs\n", last_year)))?;
 
-    let main = String::from_utf8(read(format!("src/{}/main.rs", last_year))?)?;
+    let main = String::from_utf8(read(format!("src/{}/mod.rs", last_year))?)?;
     let index = main
         .find("// modules\n")
         .ok_or(NextError::ModulesNotFound {
@@ -95,36 +108,107 @@ fn add_day(last_year: u32, day: u32, stdout: &mut Stdout) -> Result<(), NextErro
         + 1;
     let main = format!("{}mod q{:02};\n{}", &main[..next], day, &main[next..]);
 
-    let index = main.find("q_vec!(").ok_or(NextError::ModulesNotFound {
-        location: "qvec start".to_owned(),
-    })?;
-    let next = index
-        + main[index..]
-            .find(");\n")
-            .ok_or(NextError::ModulesNotFound {
-                location: "qvec end".to_owned(),
-            })?;
-    let main = format!("{} q{:02},{}", &main[..next], day, &main[next..]);
+    let main = add_to_q_vec(&main, day)?;
 
-    let mut main_out = File::create(format!("src/{}/main.rs", last_year))?;
+    let mut main_out = File::create(format!("src/{}/mod.rs", last_year))?;
     main_out.write_all(main.as_bytes())?;
 
     Ok(())
 }
 
+/// Add "q<:02=day>" after the last entry in the `q_vec!`, whether or not the list
+/// already ends with a comma, or is spread over several lines.
+fn add_to_q_vec(main: &str, day: u32) -> Result<String, NextError> {
+    let index = main.find("q_vec!(").ok_or(NextError::ModulesNotFound {
+        location: "qvec start".to_owned(),
+    })? + "q_vec!(".len();
+    let end = index
+        + main[index..].find(')').ok_or(NextError::ModulesNotFound {
+            location: "qvec end".to_owned(),
+        })?;
+    let entries = main[index..end].trim_end();
+    let next = index + entries.len();
+    let separator = if entries.is_empty() || entries.ends_with(',') {
+        ""
+    } else {
+        ","
+    };
+    let space = if entries.is_empty() { "" } else { " " };
+    Ok(format!(
+        "{}{}{}q{:02},{}",
+        &main[..next],
+        separator,
+        space,
+        day,
+        &main[next..]
+    ))
+}
+
 fn add_year(year: u32, stdout: &mut Stdout) -> Result<(), NextError> {
     stdout.execute(Print(format!("Adding Year: {}\n", year)))?;
     create_dir_all(format!("src/{}/data", year))?;
-    copy("template/main.rs", format!("src/{}/main.rs", year))?;
+    let template = String::from_utf8(read("template/main.rs")?)?;
+    let template = template.replace("XXXX", &format!("{}", year));
+    let mut main = File::create(format!("src/{}/main.rs", year))?;
+    main.write_all(template.as_bytes())?;
+    copy("template/mod.rs", format!("src/{}/mod.rs", year))?;
+    add_to_aoc(year, stdout)?;
     add_day(year, 1, stdout)?;
     Ok(())
 }
 
+fn add_to_aoc(year: u32, stdout: &mut Stdout) -> Result<(), NextError> {
+    // Edit the unified binary to add the year's module, and "(<year>, y<year>::days())"…
+    stdout.execute(Print("Editing: aoc.rs\n"))?;
+
+    let aoc = String::from_utf8(read("src/aoc.rs")?)?;
+    let index = aoc.find("// years\n").ok_or(NextError::ModulesNotFound {
+        location: "years start".to_owned(),
+    })?;
+    let next = index
+        + aoc[index..]
+            .find("\n\n")
+            .ok_or(NextError::ModulesNotFound {
+                location: "years end".to_owned(),
+            })?
+        + 1;
+    let aoc = format!(
+        "{}#[path = \"{}/mod.rs\"]\nmod y{};\n{}",
+        &aoc[..next],
+        year,
+        year,
+        &aoc[next..]
+    );
+
+    let index = aoc
+        .find("let years = vec![")
+        .ok_or(NextError::ModulesNotFound {
+            location: "years vec start".to_owned(),
+        })?;
+    let next = index
+        + aoc[index..]
+            .find("];\n")
+            .ok_or(NextError::ModulesNotFound {
+                location: "years vec end".to_owned(),
+            })?;
+    let aoc = format!(
+        "{}    ({}, y{}::days()),\n    {}",
+        &aoc[..next],
+        year,
+        year,
+        &aoc[next..]
+    );
+
+    let mut aoc_out = File::create("src/aoc.rs")?;
+    aoc_out.write_all(aoc.as_bytes())?;
+
+    Ok(())
+}
+
 fn add_next(last_year: u32, last_day: &str, stdout: &mut Stdout) -> Result<(), NextError> {
     stdout.execute(Print("Figuring out what to do…\n"))?;
-    if last_day != "q25.rs" {
-        let last_day = &last_day[1..3];
-        let last_day = last_day.parse::<u32>()?;
+    let last_day = last_day[1..3].parse::<u32>()?;
+    if last_day < calendar::puzzle_count(last_year as i32) {
         add_day(last_year, last_day + 1, stdout)?;
     } else {
         add_year(last_year + 1, stdout)?;
@@ -133,40 +217,122 @@ fn add_next(last_year: u32, last_day: &str, stdout: &mut Stdout) -> Result<(), N
     Ok(())
 }
 
-fn get_url(year: i32, day: u32) -> Result<Response, NextError> {
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-    let mut request = Client::new().get(url);
-    let session_cookie = format!("session={}", &var("AOC_SESSION")?);
-    request = request.header(COOKIE, session_cookie);
-    let response = request.send()?;
-    Ok(response)
+fn input_path(year: i32, day: u32) -> String {
+    format!("/{}/day/{}/input", year, day)
 }
 
-fn get_all_inputs() -> Result<(), NextError> {
-    let today = Local::now().naive_local();
-    let current_year = today.year();
-    let current_day = today.day();
+/// Where the inputs go: next to the questions for our own account, and under `inputs/`
+/// for anyone else's, ready for `aoc --data-dir inputs/<account>`.
+fn data_dir(account: Option<&str>, year: i32) -> String {
+    match account {
+        Some(account) => format!("inputs/{}/{}", account, year),
+        None => format!("src/{}/data", year),
+    }
+}
 
-    for year in 2015..current_year {
-        create_dir_all(format!("src/{}/data", year))?;
-        for day in 1..=25 {
-            if download_input(year, day)? {
-                std::thread::sleep(std::time::Duration::from_secs(2));
-            }
+/// Make sure the sessions still work: just `account`'s if there is one, or everyone's.
+fn check_sessions(account: Option<&str>) -> Result<(), NextError> {
+    let accounts = Accounts::load()?;
+    let mut names = match account {
+        Some(account) => vec![Some(account)],
+        None => accounts.names().into_iter().map(Some).collect(),
+    };
+    if account.is_none() && std::env::var_os(ENV_ACCOUNT).is_some() {
+        names.insert(0, None);
+    }
+    if names.is_empty() {
+        // This will explain where the sessions should go.
+        accounts.session(None)?;
+    }
+    for name in names {
+        let result = Client::for_account(name).and_then(|client| {
+            client.check_session()?;
+            Ok(client.account().unwrap_or(ENV_ACCOUNT).to_owned())
+        });
+        match result {
+            Ok(name) => println!("{}: ok", name),
+            Err(error) => println!("{}: {}", name.unwrap_or(ENV_ACCOUNT), error),
         }
     }
-    let year = current_year;
+    Ok(())
+}
+
+/// Split a puzzle like `2025/12a` into its year, day, and part.
+fn parse_puzzle_name(puzzle: &str) -> Option<(u32, u32, Part)> {
+    let (year, day) = puzzle.split_once('/')?;
+    let (day, part) = day.split_at(day.len().checked_sub(1)?);
+    let part = match part {
+        "a" | "A" => Part::A,
+        "b" | "B" => Part::B,
+        _ => return None,
+    };
+    Some((year.parse().ok()?, day.parse().ok()?, part))
+}
+
+/// Send `answer` to the server, unless we already know what it'll say about it.
+fn submit(puzzle: &str, answer: &str) -> Result<(), NextError> {
+    let (year, day, part) = parse_puzzle_name(puzzle).ok_or(NextError::BadPuzzle {
+        puzzle: puzzle.to_owned(),
+    })?;
+    if !calendar::has_puzzle(year as i32, day) {
+        return Err(NextError::PuzzleNotFound { year, day });
+    }
+    let mut attempts = Attempts::load(year)?;
+    if let Some(outcome) = attempts.check(day, part, answer) {
+        println!(
+            "Not submitting {} for {}, since we already know it's {}.",
+            answer, puzzle, outcome
+        );
+        return Ok(());
+    }
+
+    println!("Submitting {} for {}", answer, puzzle);
+    let level = match part {
+        Part::A => "1",
+        Part::B => "2",
+    };
+    let response = Client::new()?.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", level), ("answer", answer)],
+    )?;
+    let outcome = parse_response(&response);
+    println!("  The answer was {}.", outcome);
+
     create_dir_all(format!("src/{}/data", year))?;
-    for day in 1..=current_day {
-        if download_input(year, day)? {
-            std::thread::sleep(std::time::Duration::from_secs(2));
+    attempts.record(day, part, answer, &outcome);
+    attempts.save()?;
+    if outcome == Outcome::Correct {
+        let mut answers = Answers::load(year)?;
+        answers.set(&day.to_string(), part, answer.to_owned());
+        answers.save()?;
+        println!("  Recorded it in {:?}", Answers::path(year));
+    }
+    Ok(())
+}
+
+fn get_all_inputs(account: Option<&str>) -> Result<(), NextError> {
+    let client = Client::for_account(account)?;
+    let now = Utc::now();
+    for year in FIRST_YEAR..=now.year() {
+        let days = calendar::released_days(year, now);
+        if days.is_empty() {
+            continue;
+        }
+        create_dir_all(data_dir(account, year))?;
+        for day in days {
+            download_input(&client, account, year, day)?;
         }
     }
     Ok(())
 }
 
-fn download_input(year: i32, day: u32) -> Result<bool, NextError> {
-    let datapath = format!("src/{}/data/q{:02}.data", year, day);
+fn download_input(
+    client: &Client,
+    account: Option<&str>,
+    year: i32,
+    day: u32,
+) -> Result<bool, NextError> {
+    let datapath = format!("{}/q{:02}.data", data_dir(account, year), day);
     let metadata = std::fs::metadata(&datapath);
     if metadata.map(|m| m.len()).unwrap_or(1) == 0 {
         // If the file is empty, delete it so that we can re-download it!
@@ -179,18 +345,13 @@ fn download_input(year: i32, day: u32) -> Result<bool, NextError> {
     Ok(match file {
         Ok(mut file) => {
             println!("Getting input for {:?}", datapath);
-            let response = get_url(year, day);
-            if response.is_err() {
-                let rm = remove_file(&datapath);
-                rm?;
-            }
-            let mut response = response?;
-            if !response.status().is_success() {
-                // let rm = remove_file(&datapath);
-                // rm?;
-                return Err(NextError::InputNotFound { year, day });
+            match client.get(&input_path(year, day)) {
+                Ok(input) => file.write_all(input.as_bytes())?,
+                Err(error) => {
+                    remove_file(&datapath)?;
+                    return Err(error.into());
+                }
             }
-            response.copy_to(&mut file)?;
             true
         }
         _ => {
@@ -200,26 +361,54 @@ fn download_input(year: i32, day: u32) -> Result<bool, NextError> {
     })
 }
 
-fn get_input() -> Result<(), NextError> {
-    let today = Local::now().naive_local();
-    let year = today.year();
-    let day = today.day();
-    let datapath = format!("src/{}/data/q{:02}.data", year, day);
-    let metadata = std::fs::metadata(datapath);
-    if metadata.map(|m| m.len()).unwrap_or(1) == 0 {
+/// Save the puzzle's description as Markdown next to its input, and put its examples
+/// into the day's tests if they're still empty.
+fn get_puzzle(client: &Client, year: i32, day: u32) -> Result<(), NextError> {
+    println!("Getting puzzle for {}/{:02}", year, day);
+    let parts = parse_puzzle(&client.get(&format!("/{}/day/{}", year, day))?);
+
+    create_dir_all(format!("src/{}/data", year))?;
+    fs::write(
+        format!("src/{}/data/q{:02}.md", year, day),
+        to_markdown(&parts),
+    )?;
+
+    let question = format!("src/{}/q{:02}.rs", year, day);
+    if let Ok(source) = fs::read_to_string(&question) {
+        let filled = fill_examples(&source, &parts);
+        if filled != source {
+            println!("  Adding examples to {:?}", question);
+            fs::write(&question, filled)?;
+        }
+    }
+    Ok(())
+}
+
+fn get_input(account: Option<&str>) -> Result<(), NextError> {
+    let client = Client::for_account(account)?;
+    let now = Utc::now();
+    let year = now.year();
+    let today = calendar::released_days(year, now).last().copied();
+    let missing = today.filter(|day| {
+        let datapath = format!("{}/q{:02}.data", data_dir(account, year), day);
+        let metadata = std::fs::metadata(datapath);
+        metadata.map(|m| m.len()).unwrap_or(0) == 0
+    });
+    if let Some(day) = missing {
         // We're getting the data for today!
-        download_input(year, day)?;
+        create_dir_all(data_dir(account, year))?;
+        download_input(&client, account, year, day)?;
+        if account.is_none() {
+            get_puzzle(&client, year, day)?;
+        }
     } else {
-        // Let's wait and get the data for tomorrow!
-        let tomorrow = today
-            .date()
-            .succ_opt()
-            .ok_or(NextError::YearNotFound { year })?;
-        let day = tomorrow.day();
-        let datapath = format!("src/{}/data/q{:02}.data", year, day);
+        // Let's wait and get the data for the next puzzle, if there is one!
+        let (day, unlock) =
+            calendar::next_unlock(year, now).ok_or(NextError::NoMorePuzzles { year })?;
+        let datapath = format!("{}/q{:02}.data", data_dir(account, year), day);
         println!("Getting input for {:?}", datapath);
-        let tonight = tomorrow.and_hms_opt(0, 0, 1).unwrap();
-        let duration = tonight - today;
+        let tonight = unlock + Duration::seconds(1);
+        let duration = tonight - now;
         let std_duration = std::time::Duration::from_secs(
             duration
                 .to_std()
@@ -229,27 +418,18 @@ fn get_input() -> Result<(), NextError> {
         println!(
             "  Sleeping for {} until {}",
             format_duration(std_duration),
-            tonight
+            tonight.with_timezone(&Local)
         );
         std::thread::sleep(std_duration);
         println!("  Waking up and getting the response!");
-        let mut response = get_url(year, day)?;
-        if !response.status().is_success() {
-            // try again in 5 seconds…
-            println!("  Trying again in {:?}!", Duration::seconds(5).to_std());
-            std::thread::sleep(
-                Duration::seconds(5)
-                    .to_std()
-                    .map_err(|_| NextError::DurationError { duration })?,
-            );
-            println!("  Waking up and getting the response!");
-            response = get_url(year, day)?;
-            if !response.status().is_success() {
-                return Err(NextError::InputNotFound { year, day });
-            }
+        // The input sometimes isn't quite ready right at midnight, so keep trying for a bit.
+        let client = client.retry_on(StatusCode::NOT_FOUND);
+        let input = client.get(&input_path(year, day))?;
+        create_dir_all(data_dir(account, year))?;
+        fs::write(&datapath, input)?;
+        if account.is_none() {
+            get_puzzle(&client, year, day)?;
         }
-        let mut file = File::create(&datapath)?;
-        response.copy_to(&mut file)?;
     }
     Ok(())
 }
@@ -263,6 +443,7 @@ fn main() -> Result<(), NextError> {
                 .short('y')
                 .help("Which year to add")
                 .long_help("Specify a year to add.")
+                .value_parser(value_parser!(u32))
                 .group("arg"),
         )
         .arg(
@@ -270,6 +451,7 @@ fn main() -> Result<(), NextError> {
                 .short('d')
                 .help("Which day to add")
                 .long_help("Specify a day to add.")
+                .value_parser(value_parser!(u32))
                 .group("arg"),
         )
         .arg(
@@ -280,6 +462,30 @@ fn main() -> Result<(), NextError> {
                 .action(ArgAction::SetTrue)
                 .group("arg"),
         )
+        .arg(
+            Arg::new("puzzle")
+                .short('p')
+                .help("Get the puzzle")
+                .long_help(
+                    "Get the latest puzzle's description as Markdown, and fill in its tests' examples.",
+                )
+                .action(ArgAction::SetTrue)
+                .group("arg"),
+        )
+        .arg(
+            Arg::new("submit")
+                .short('s')
+                .long("submit")
+                .help("Submit an answer")
+                .long_help(
+                    "Submit an answer for part of a puzzle, like `-s 2025/12a 1234`.
+ Answers the server has already rejected, or that are past one it said was too high or too low,
+ aren't sent again.",
+                )
+                .num_args(2)
+                .value_names(["puzzle", "answer"])
+                .group("arg"),
+        )
         .arg(
             Arg::new("all_inputs")
                 .short('a')
@@ -288,9 +494,34 @@ fn main() -> Result<(), NextError> {
                 .action(ArgAction::SetTrue)
                 .group("arg"),
         )
+        .arg(
+            Arg::new("check")
+                .short('c')
+                .long("check")
+                .help("Check the session tokens")
+                .long_help(
+                    "Check that the session tokens still work, either the --account's,
+ or every account's in the config file.",
+                )
+                .action(ArgAction::SetTrue)
+                .group("arg"),
+        )
+        .arg(
+            Arg::new("account")
+                .short('u')
+                .long("account")
+                .help("Use another account's session")
+                .long_help(
+                    "Use the session for this account from the config file,
+ and put its inputs in inputs/<account>/<year> instead of src/<year>/data.",
+                )
+                .value_name("name")
+                .conflicts_with_all(["submit", "puzzle"]),
+        )
         .get_matches();
 
     let (last_year, last_day) = get_last()?;
+    let account = matches.get_one::<String>("account").map(String::as_str);
 
     match matches.get_one::<u32>("day") {
         Some(&day) => {
@@ -301,10 +532,19 @@ fn main() -> Result<(), NextError> {
                 add_year(year, &mut stdout)?;
             }
             _ => {
-                if *matches.get_one("input").unwrap() {
-                    get_input()?;
+                if let Some(mut values) = matches.get_many::<String>("submit") {
+                    let puzzle = values.next().unwrap();
+                    let answer = values.next().unwrap();
+                    submit(puzzle, answer)?;
+                } else if *matches.get_one("input").unwrap() {
+                    get_input(account)?;
+                } else if *matches.get_one("puzzle").unwrap() {
+                    let (year, day) = calendar::latest_puzzle(Utc::now());
+                    get_puzzle(&Client::new()?, year, day)?;
                 } else if *matches.get_one("all_inputs").unwrap() {
-                    get_all_inputs()?;
+                    get_all_inputs(account)?;
+                } else if *matches.get_one("check").unwrap() {
+                    check_sessions(account)?;
                 } else {
                     add_next(last_year, &last_day, &mut stdout)?;
                 }
@@ -315,3 +555,21 @@ fn main() -> Result<(), NextError> {
     stdout.flush()?;
     Ok(())
 }
+
+#[test]
+fn test_add_to_q_vec() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        add_to_q_vec("    q_vec!()\n", 1).unwrap(),
+        "    q_vec!(q01,)\n"
+    );
+    assert_eq!(
+        add_to_q_vec("    q_vec!(q01, q02,)\n", 3).unwrap(),
+        "    q_vec!(q01, q02, q03,)\n"
+    );
+    assert_eq!(
+        add_to_q_vec("    q_vec!(\n        q01, q02,\n        q03\n    )\n", 4).unwrap(),
+        "    q_vec!(\n        q01, q02,\n        q03, q04,\n    )\n"
+    );
+}
@@ -0,0 +1,310 @@
+/// One part of a day's puzzle, from one of the page's `<article>`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Part {
+    pub markdown: String,
+    /// The contents of the part's `<pre><code>` blocks.
+    pub examples: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut rv = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let Some(end) = rest.find('>') else {
+                    rv.push(Token::Text(rest));
+                    break;
+                };
+                let tag = rest[1..end].trim();
+                rv.push(match tag.strip_prefix('/') {
+                    Some(name) => Token::Close(name.trim()),
+                    None => {
+                        let tag = tag.trim_end_matches('/').trim_end();
+                        let (name, attributes) =
+                            tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                        Token::Open(name, attributes)
+                    }
+                });
+                rest = &rest[end + 1..];
+            }
+            Some(start) => {
+                rv.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                rv.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+    rv
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let key = format!("{}=\"", name);
+    let start = attributes
+        .match_indices(&key)
+        .find(|&(i, _)| i == 0 || attributes[..i].ends_with(char::is_whitespace))?
+        .0
+        + key.len();
+    let end = attributes[start..].find('"')? + start;
+    Some(&attributes[start..end])
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut rv = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        rv.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let number = entity.strip_prefix('#')?;
+                    let value = match number.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => number.parse().ok()?,
+                    };
+                    char::from_u32(value)?
+                }
+            };
+            Some((c, end + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                rv.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                rv.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    rv.push_str(rest);
+    rv
+}
+
+/// Convert one `<article>` to Markdown, collecting its examples along the way.
+fn render(article: &str) -> Part {
+    let mut out = String::new();
+    let mut examples = vec![];
+    let mut pre: Option<String> = None;
+    let mut code = false;
+    let mut links = vec![];
+    let mut lists = 0_usize;
+
+    for token in tokens(article) {
+        if let Some(block) = &mut pre {
+            match token {
+                Token::Close("pre") => {
+                    let block = pre.take().unwrap_or_default();
+                    out.push_str("```\n");
+                    out.push_str(&block);
+                    if !block.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                    examples.push(block);
+                }
+                Token::Text(text) => block.push_str(&decode_entities(text)),
+                _ => {}
+            }
+            continue;
+        }
+        match token {
+            Token::Open("h2", _) => out.push_str("## "),
+            Token::Close("h2" | "p") => out.push_str("\n\n"),
+            Token::Open("pre", _) => pre = Some(String::new()),
+            Token::Open("code", _) => {
+                code = true;
+                out.push('`');
+            }
+            Token::Close("code") => {
+                code = false;
+                out.push('`');
+            }
+            // Markdown can't emphasise inside code, so the highlighting is lost there.
+            Token::Open("em", _) | Token::Close("em") if !code => out.push('*'),
+            Token::Open("ul", _) => lists += 1,
+            Token::Close("ul") => {
+                lists = lists.saturating_sub(1);
+                if lists == 0 {
+                    out.push('\n');
+                }
+            }
+            Token::Open("li", _) => {
+                if !out.is_empty() && !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str(&"  ".repeat(lists.saturating_sub(1)));
+                out.push_str("- ");
+            }
+            Token::Close("li") => out.push('\n'),
+            Token::Open("a", attributes) => {
+                let href = attribute(attributes, "href").unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("https://adventofcode.com{}", href)
+                } else {
+                    href.to_owned()
+                };
+                links.push(href);
+                out.push('[');
+            }
+            Token::Close("a") => {
+                if let Some(href) = links.pop() {
+                    out.push_str(&format!("]({})", href));
+                }
+            }
+            Token::Text(text) => {
+                if (out.is_empty() || out.ends_with('\n')) && text.trim().is_empty() {
+                    continue;
+                }
+                out.push_str(&decode_entities(text).replace('\n', " "));
+            }
+            _ => {}
+        }
+    }
+    Part {
+        markdown: out.trim_end().to_owned() + "\n",
+        examples,
+    }
+}
+
+/// Pull the description out of a puzzle page, one part per `<article>`.
+pub fn parse_puzzle(html: &str) -> Vec<Part> {
+    let mut parts = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let article = &rest[start..start + end];
+        let body = article.find('>').map_or("", |i| &article[i + 1..]);
+        parts.push(render(body));
+        rest = &rest[start + end + "</article>".len()..];
+    }
+    parts
+}
+
+/// All the parts of a puzzle as one Markdown document.
+pub fn to_markdown(parts: &[Part]) -> String {
+    parts
+        .iter()
+        .map(|part| part.markdown.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Write `example` as a string for `indoc!`, indented to sit inside a test.
+fn example_literal(example: &str) -> String {
+    let mut rv = String::from("\"\n");
+    for line in example.lines() {
+        if !line.is_empty() {
+            rv.push_str("        ");
+            rv.push_str(&line.replace('\\', "\\\\").replace('"', "\\\""));
+        }
+        rv.push('\n');
+    }
+    rv.push_str("    \"");
+    rv
+}
+
+/// Fill the template's empty `indoc!("")` test slots with the first example from each part.
+/// Part two usually reuses part one's example, so that's the fallback for both slots.
+pub fn fill_examples(source: &str, parts: &[Part]) -> String {
+    let mut source = source.to_owned();
+    for (i, name) in ["a", "b"].into_iter().enumerate() {
+        let example = parts
+            .get(i)
+            .and_then(|part| part.examples.first())
+            .or_else(|| parts.first()?.examples.first());
+        if let Some(example) = example {
+            let slot = format!("process_data_{}(indoc!(\"\"))", name);
+            let filled = format!(
+                "process_data_{}(indoc!({}))",
+                name,
+                example_literal(example)
+            );
+            source = source.replacen(&slot, &filled, 1);
+        }
+    }
+    source
+}
+
+#[test]
+fn test_puzzle() {
+    use pretty_assertions::assert_eq;
+
+    let parts = parse_puzzle(include_str!("fixtures/puzzle.html"));
+    assert_eq!(parts.len(), 2);
+    assert_eq!(
+        parts[0].markdown,
+        indoc::indoc!(
+            r#"
+            ## --- Day 3: Sock Drawer ---
+
+            The elves keep their socks in a [drawer](https://adventofcode.com/2015/day/3) that is sorted *mostly* by colour. Each line lists a sock's `colour` and `size`:
+
+            ```
+            red 10
+            blue 12
+            red 10
+            ```
+
+            Pairs are socks with the same colour *and* size:
+
+            - A pair scores its size.
+            - Socks without a pair score `0` & go <back> in the drawer.
+
+            What is the total score of all the pairs, like `10` above?
+            "#
+        )
+    );
+    assert_eq!(parts[0].examples, vec!["red 10\nblue 12\nred 10\n"]);
+    assert_eq!(
+        parts[1],
+        Part {
+            markdown: "## --- Part Two ---\n\nNow only count the red pairs.\n".to_string(),
+            examples: vec![],
+        }
+    );
+
+    let template = indoc::indoc!(
+        r#"
+        assert_eq!(process_data_a(indoc!("")), 0);
+        assert_eq!(process_data_b(indoc!("")), 0);
+        "#
+    );
+    assert_eq!(
+        fill_examples(template, &parts),
+        indoc::indoc!(
+            r#"
+            assert_eq!(process_data_a(indoc!("
+                    red 10
+                    blue 12
+                    red 10
+                ")), 0);
+            assert_eq!(process_data_b(indoc!("
+                    red 10
+                    blue 12
+                    red 10
+                ")), 0);
+            "#
+        )
+    );
+}
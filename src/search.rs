@@ -0,0 +1,500 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque, hash_map::Entry},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Keeps track of every node we've seen, so the searches only need `N: Eq + Hash + Clone`.
+#[derive(Debug)]
+struct Nodes<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Nodes<N> {
+    fn new() -> Self {
+        Nodes {
+            nodes: vec![],
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Returns the node's index, and whether we'd seen it before.
+    fn insert(&mut self, node: N) -> (usize, bool) {
+        match self.indices.entry(node) {
+            Entry::Occupied(entry) => (*entry.get(), true),
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                entry.insert(index);
+                (index, false)
+            }
+        }
+    }
+
+    fn path(&self, parents: &[Option<usize>], mut index: usize) -> Vec<N> {
+        let mut rv = vec![self.nodes[index].clone()];
+        while let Some(parent) = parents[index] {
+            rv.push(self.nodes[parent].clone());
+            index = parent;
+        }
+        rv.reverse();
+        rv
+    }
+}
+
+/// Breadth-first search from any of `starts` to the nearest node satisfying `is_goal`.
+/// Returns the path, including the start and the goal, so the number of steps is `len() - 1`.
+pub fn bfs<N, FN, IN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: FN,
+    mut is_goal: FG,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new();
+    let mut parents = vec![];
+    let mut queue = VecDeque::new();
+    for start in starts {
+        let (index, seen) = nodes.insert(start);
+        if !seen {
+            parents.push(None);
+            queue.push_back(index);
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let current = nodes.nodes[index].clone();
+        if is_goal(&current) {
+            return Some(nodes.path(&parents, index));
+        }
+        for next in neighbours(&current) {
+            let (next, seen) = nodes.insert(next);
+            if !seen {
+                parents.push(Some(index));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from the nearest of `starts` to every reachable node,
+/// stopping after `max_depth` steps if there is one (for infinite graphs).
+pub fn bfs_distances<N, FN, IN>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: FN,
+    max_depth: Option<usize>,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut rv = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if rv.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((current, depth)) = queue.pop_front() {
+        if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            continue;
+        }
+        for next in neighbours(&current) {
+            if let Entry::Vacant(entry) = rv.entry(next.clone()) {
+                entry.insert(depth + 1);
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+    rv
+}
+
+/// An entry in the priority queue, ordered so that `BinaryHeap` pops the cheapest first.
+#[derive(Debug, PartialEq, Eq)]
+struct State<C> {
+    priority: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for State<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Reverse(&self.priority)
+            .cmp(&Reverse(&other.priority))
+            // Prefer the deeper node when the estimates are tied, to reach the goal sooner.
+            .then_with(|| self.cost.cmp(&other.cost))
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<C: Ord> PartialOrd for State<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A* search from any of `starts` to the cheapest node satisfying `is_goal`.
+/// `heuristic` must never overestimate the remaining cost, or the path may not be the shortest.
+/// Returns the path, including the start and the goal, and its total cost.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new();
+    let mut parents = vec![];
+    let mut costs = vec![];
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let priority = heuristic(&start);
+        let (index, seen) = nodes.insert(start);
+        if !seen {
+            parents.push(None);
+            costs.push(C::default());
+            queue.push(State {
+                priority,
+                cost: C::default(),
+                index,
+            });
+        }
+    }
+
+    while let Some(State { cost, index, .. }) = queue.pop() {
+        if cost > costs[index] {
+            // We've already found a cheaper way here.
+            continue;
+        }
+        let current = nodes.nodes[index].clone();
+        if is_goal(&current) {
+            return Some((nodes.path(&parents, index), cost));
+        }
+        for (next, step) in neighbours(&current) {
+            let next_cost = cost + step;
+            let priority = next_cost + heuristic(&next);
+            let (next, seen) = nodes.insert(next);
+            if !seen {
+                parents.push(Some(index));
+                costs.push(next_cost);
+            } else if next_cost < costs[next] {
+                parents[next] = Some(index);
+                costs[next] = next_cost;
+            } else {
+                continue;
+            }
+            queue.push(State {
+                priority,
+                cost: next_cost,
+                index: next,
+            });
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm from any of `starts` to the cheapest node satisfying `is_goal`.
+/// Returns the path, including the start and the goal, and its total cost.
+pub fn dijkstra<N, C, FN, IN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: FN,
+    is_goal: FG,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// The cheapest cost from the nearest of `starts` to every reachable node.
+pub fn dijkstra_costs<N, C, FN, IN>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: FN,
+) -> HashMap<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new();
+    let mut costs = vec![];
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let (index, seen) = nodes.insert(start);
+        if !seen {
+            costs.push(C::default());
+            queue.push(State {
+                priority: C::default(),
+                cost: C::default(),
+                index,
+            });
+        }
+    }
+
+    while let Some(State { cost, index, .. }) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        let current = nodes.nodes[index].clone();
+        for (next, step) in neighbours(&current) {
+            let next_cost = cost + step;
+            let (next, seen) = nodes.insert(next);
+            if !seen {
+                costs.push(next_cost);
+            } else if next_cost < costs[next] {
+                costs[next] = next_cost;
+            } else {
+                continue;
+            }
+            queue.push(State {
+                priority: next_cost,
+                cost: next_cost,
+                index: next,
+            });
+        }
+    }
+    nodes.nodes.into_iter().zip(costs).collect()
+}
+
+/// Every cheapest path to the goals found by `all_shortest_paths`.
+#[derive(Debug)]
+pub struct ShortestPaths<N, C> {
+    pub cost: C,
+    nodes: Nodes<N>,
+    parents: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<N: Eq + Hash + Clone, C> ShortestPaths<N, C> {
+    /// The goals that can be reached for the cheapest cost.
+    pub fn goals(&self) -> Vec<N> {
+        self.goals
+            .iter()
+            .map(|&goal| self.nodes.nodes[goal].clone())
+            .collect()
+    }
+
+    /// Every node that lies on at least one of the cheapest paths.
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(index) = stack.pop() {
+            if seen.insert(index) {
+                stack.extend(&self.parents[index]);
+            }
+        }
+        seen.into_iter()
+            .map(|index| self.nodes.nodes[index].clone())
+            .collect()
+    }
+
+    /// Each of the cheapest paths, from a start to a goal.
+    /// There can be exponentially many of these, so prefer `nodes` when that's enough.
+    pub fn paths(&self) -> Vec<Vec<N>> {
+        let mut rv = vec![];
+        let mut stack: Vec<Vec<usize>> = self.goals.iter().map(|&goal| vec![goal]).collect();
+        while let Some(path) = stack.pop() {
+            let last = *path.last().unwrap();
+            if self.parents[last].is_empty() {
+                rv.push(
+                    path.iter()
+                        .rev()
+                        .map(|&index| self.nodes.nodes[index].clone())
+                        .collect(),
+                );
+            }
+            for &parent in &self.parents[last] {
+                let mut next = path.clone();
+                next.push(parent);
+                stack.push(next);
+            }
+        }
+        rv
+    }
+}
+
+/// Dijkstra's algorithm, keeping track of every cheapest path to every goal with the cheapest cost.
+pub fn all_shortest_paths<N, C, FN, IN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: FN,
+    mut is_goal: FG,
+) -> Option<ShortestPaths<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new();
+    let mut parents: Vec<Vec<usize>> = vec![];
+    let mut costs = vec![];
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let (index, seen) = nodes.insert(start);
+        if !seen {
+            parents.push(vec![]);
+            costs.push(C::default());
+            queue.push(State {
+                priority: C::default(),
+                cost: C::default(),
+                index,
+            });
+        }
+    }
+
+    let mut best = None;
+    let mut goals = vec![];
+    while let Some(State { cost, index, .. }) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if cost > costs[index] {
+            continue;
+        }
+        let current = nodes.nodes[index].clone();
+        if is_goal(&current) {
+            if !goals.contains(&index) {
+                best = Some(cost);
+                goals.push(index);
+            }
+            continue;
+        }
+        for (next, step) in neighbours(&current) {
+            let next_cost = cost + step;
+            let (next, seen) = nodes.insert(next);
+            if !seen {
+                parents.push(vec![index]);
+                costs.push(next_cost);
+            } else {
+                match next_cost.cmp(&costs[next]) {
+                    Ordering::Less => {
+                        parents[next] = vec![index];
+                        costs[next] = next_cost;
+                    }
+                    Ordering::Equal => {
+                        if !parents[next].contains(&index) {
+                            parents[next].push(index);
+                        }
+                        continue;
+                    }
+                    Ordering::Greater => continue,
+                }
+            }
+            queue.push(State {
+                priority: next_cost,
+                cost: next_cost,
+                index: next,
+            });
+        }
+    }
+
+    best.map(|cost| ShortestPaths {
+        cost,
+        nodes,
+        parents,
+        goals,
+    })
+}
+
+#[test]
+fn test_bfs() {
+    use pretty_assertions::assert_eq;
+
+    // A 5x5 grid with a wall down the middle, leaving a gap at the bottom.
+    let open =
+        |&(x, y): &(i64, i64)| (0..5).contains(&x) && (0..5).contains(&y) && (x != 2 || y == 4);
+    let neighbours = |&(x, y): &(i64, i64)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(open)
+            .collect::<Vec<_>>()
+    };
+
+    let path = bfs([(0, 0)], neighbours, |&node| node == (4, 0)).unwrap();
+    assert_eq!(path.len() - 1, 12);
+    assert_eq!(path[0], (0, 0));
+    assert_eq!(path[12], (4, 0));
+    assert_eq!(
+        bfs([(0, 0), (4, 4)], neighbours, |&node| node == (4, 0))
+            .unwrap()
+            .len()
+            - 1,
+        4
+    );
+    assert_eq!(bfs([(0, 0)], neighbours, |&node| node == (2, 0)), None);
+
+    let distances = bfs_distances([(0, 0)], neighbours, Some(2));
+    assert_eq!(distances.len(), 5);
+    assert_eq!(distances[&(1, 1)], 2);
+}
+
+#[test]
+fn test_dijkstra() {
+    use pretty_assertions::assert_eq;
+
+    //  a -1-> b -1-> c -1-> d
+    //  a ------5-----------> d
+    //  a -2-> e -2-> d
+    let edges = |node: &char| -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5), ('e', 2)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            'e' => vec![('d', 2)],
+            _ => vec![],
+        }
+    };
+
+    assert_eq!(
+        dijkstra(['a'], edges, |&node| node == 'd'),
+        Some((vec!['a', 'b', 'c', 'd'], 3))
+    );
+    assert_eq!(
+        astar(
+            ['a'],
+            edges,
+            |&node| if node == 'd' { 0 } else { 1 },
+            |&node| node == 'd'
+        ),
+        Some((vec!['a', 'b', 'c', 'd'], 3))
+    );
+    assert_eq!(dijkstra_costs(['a'], edges)[&'d'], 3);
+
+    let edges = |node: &char| -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('e', 2)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 2)],
+            'e' => vec![('d', 2)],
+            _ => vec![],
+        }
+    };
+    let paths = all_shortest_paths(['a'], edges, |&node| node == 'd').unwrap();
+    assert_eq!(paths.cost, 4);
+    assert_eq!(paths.nodes(), HashSet::from(['a', 'b', 'c', 'd', 'e']));
+    let mut all = paths.paths();
+    all.sort();
+    assert_eq!(all, vec![vec!['a', 'b', 'c', 'd'], vec!['a', 'e', 'd']]);
+}
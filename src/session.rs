@@ -0,0 +1,223 @@
+use std::{
+    collections::BTreeMap,
+    env::{var, var_os},
+    fs::File,
+    io::BufReader,
+    path::PathBuf,
+};
+
+use custom_error::custom_error;
+use serde_json::Value;
+
+custom_error! { pub SessionError
+    IOError{source: std::io::Error} = "io error",
+    JSON{source: serde_json::error::Error} = "json error",
+    NoSession{path: String} = "No session token found. Set AOC_SESSION, or add an account to {path}.",
+    UnknownAccount{account: String, path: String} = "There's no account called {account} in {path}.",
+    InvalidToken{account: String} = "The session token for {account} doesn't look right. It should be the hex string from adventofcode.com's session cookie.",
+    BadConfig{path: String} = "Couldn't understand {path}. It can have an \"accounts\" object of names and tokens, a \"default\" name, and a \"leaderboards\" list of IDs.",
+}
+
+/// What the session from `AOC_SESSION` is called.
+pub const ENV_ACCOUNT: &str = "AOC_SESSION";
+
+/// A session cookie, and whose it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Session {
+    pub account: String,
+    pub token: String,
+}
+
+/// Where the accounts live: `AOC_CONFIG` if it's set, otherwise
+/// `adventofcode/sessions.json` in the user's config directory.
+pub fn config_path() -> PathBuf {
+    if let Some(path) = var_os("AOC_CONFIG") {
+        return PathBuf::from(path);
+    }
+    let config = var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_default();
+    config.join("adventofcode").join("sessions.json")
+}
+
+/// Tidy up a pasted token (people often copy the `session=` too), and check it looks like one.
+pub fn validate_token(account: &str, token: &str) -> Result<String, SessionError> {
+    let token = token.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    if token.len() < 32 || !token.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(SessionError::InvalidToken {
+            account: account.to_owned(),
+        });
+    }
+    Ok(token.to_owned())
+}
+
+/// Whether the server sent back a "please log in" page instead of what we asked for,
+/// which is what happens once a session expires.
+pub fn is_logged_out(body: &str) -> bool {
+    body.contains("Please log in") || body.contains("/auth/login")
+}
+
+/// The named accounts from the config file, and the private leaderboards `stats` shows.
+/// It looks like
+/// `{"default": "me", "accounts": {"me": "53616c7465...", "them": "..."}, "leaderboards": [70644]}`.
+#[derive(Debug, Default)]
+pub struct Accounts {
+    path: PathBuf,
+    default: Option<String>,
+    accounts: BTreeMap<String, String>,
+    leaderboards: Vec<u64>,
+}
+
+impl Accounts {
+    /// Load the accounts from `config_path()`. It's fine for there not to be any.
+    pub fn load() -> Result<Self, SessionError> {
+        let path = config_path();
+        if !path.is_file() {
+            return Ok(Accounts {
+                path,
+                ..Default::default()
+            });
+        }
+        let config: Value = serde_json::from_reader(BufReader::new(File::open(&path)?))?;
+        Accounts::from_config(path, &config)
+    }
+
+    fn from_config(path: PathBuf, config: &Value) -> Result<Self, SessionError> {
+        let bad_config = || SessionError::BadConfig {
+            path: path.display().to_string(),
+        };
+        let accounts = match &config["accounts"] {
+            Value::Null => BTreeMap::new(),
+            accounts => accounts
+                .as_object()
+                .ok_or_else(bad_config)?
+                .iter()
+                .map(|(name, token)| Some((name.clone(), token.as_str()?.to_owned())))
+                .collect::<Option<_>>()
+                .ok_or_else(bad_config)?,
+        };
+        let default = match &config["default"] {
+            Value::Null => None,
+            default => Some(default.as_str().ok_or_else(bad_config)?.to_owned()),
+        };
+        // IDs are just as often pasted as strings as numbers.
+        let leaderboards = match &config["leaderboards"] {
+            Value::Null => vec![],
+            leaderboards => leaderboards
+                .as_array()
+                .ok_or_else(bad_config)?
+                .iter()
+                .map(|id| id.as_u64().or_else(|| id.as_str()?.trim().parse().ok()))
+                .collect::<Option<_>>()
+                .ok_or_else(bad_config)?,
+        };
+        Ok(Accounts {
+            path,
+            default,
+            accounts,
+            leaderboards,
+        })
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.accounts.keys().map(String::as_str).collect()
+    }
+
+    pub fn leaderboards(&self) -> &[u64] {
+        &self.leaderboards
+    }
+
+    /// The session for `account`. Without one, that's `AOC_SESSION` if it's set,
+    /// or else the default account (which doesn't need naming if it's the only one).
+    pub fn session(&self, account: Option<&str>) -> Result<Session, SessionError> {
+        self.pick(account, var(ENV_ACCOUNT).ok().as_deref())
+    }
+
+    fn pick(
+        &self,
+        account: Option<&str>,
+        env_token: Option<&str>,
+    ) -> Result<Session, SessionError> {
+        let account = match (account, env_token) {
+            (Some(account), _) => account,
+            (None, Some(token)) => {
+                return Ok(Session {
+                    account: ENV_ACCOUNT.to_owned(),
+                    token: validate_token(ENV_ACCOUNT, token)?,
+                });
+            }
+            (None, None) => match (&self.default, self.accounts.len()) {
+                (Some(default), _) => default.as_str(),
+                (None, 1) => self.accounts.keys().next().unwrap(),
+                _ => {
+                    return Err(SessionError::NoSession {
+                        path: self.path.display().to_string(),
+                    });
+                }
+            },
+        };
+        let token = self
+            .accounts
+            .get(account)
+            .ok_or_else(|| SessionError::UnknownAccount {
+                account: account.to_owned(),
+                path: self.path.display().to_string(),
+            })?;
+        Ok(Session {
+            account: account.to_owned(),
+            token: validate_token(account, token)?,
+        })
+    }
+}
+
+#[test]
+fn test_session() {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    let mine = "53616c7465645f5f0123456789abcdef";
+    let theirs = "0123456789abcdef0123456789abcdef";
+    assert_eq!(
+        validate_token("me", &format!(" session={}\n", mine)).unwrap(),
+        mine
+    );
+    assert!(validate_token("me", "not a token").is_err());
+    assert!(validate_token("me", "abc123").is_err());
+
+    assert!(is_logged_out(
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+    ));
+    assert!(is_logged_out("<a href=\"/2025/auth/login\">[Log In]</a>"));
+    assert!(!is_logged_out(
+        "<a href=\"/2025/auth/logout\">[Log Out]</a>"
+    ));
+
+    let config = json!({"accounts": {"me": mine, "them": theirs}, "default": "them"});
+    let accounts = Accounts::from_config(PathBuf::from("sessions.json"), &config).unwrap();
+    assert_eq!(accounts.names(), vec!["me", "them"]);
+    let session = |account, token| Session {
+        account: String::from(account),
+        token: String::from(token),
+    };
+    assert_eq!(
+        accounts.pick(Some("me"), Some(theirs)).unwrap(),
+        session("me", mine)
+    );
+    assert_eq!(accounts.pick(None, None).unwrap(), session("them", theirs));
+    assert_eq!(
+        accounts.pick(None, Some(mine)).unwrap(),
+        session(ENV_ACCOUNT, mine)
+    );
+    assert!(accounts.pick(Some("you"), None).is_err());
+
+    let config = json!({"accounts": {"me": mine}, "leaderboards": [70644, "12345"]});
+    let accounts = Accounts::from_config(PathBuf::from("sessions.json"), &config).unwrap();
+    assert_eq!(accounts.pick(None, None).unwrap(), session("me", mine));
+    assert_eq!(accounts.leaderboards(), &[70644, 12345]);
+    assert!(Accounts::default().pick(None, None).is_err());
+    assert!(Accounts::from_config(PathBuf::new(), &json!({"accounts": {"me": 1}})).is_err());
+    assert!(Accounts::from_config(PathBuf::new(), &json!({"leaderboards": ["me"]})).is_err());
+}
@@ -1,143 +1,432 @@
 use std::{
     cmp::Ordering,
-    env::{VarError, var},
-    fs::{File, remove_file},
+    fs::read_to_string,
     io::{Stdout, Write, stdout},
-    path::Path,
-    time::{SystemTime, SystemTimeError},
+    path::PathBuf,
+    thread::sleep,
+    time::Duration,
 };
 
-use chrono::{Datelike, Local};
-use clap::{Arg, command};
+use aoc::{
+    calendar::{self, FIRST_YEAR},
+    client::{Client, ClientError},
+    leaderboard::{Leaderboard, Member},
+    session::{Accounts, SessionError},
+};
+use chrono::{Local, TimeZone, Utc};
+use clap::{Arg, ArgAction, ArgMatches, command, parser::ValueSource, value_parser};
 use crossterm::{
     ExecutableCommand, QueueableCommand, queue,
     style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor},
 };
 use custom_error::custom_error;
 
-use serde_json::{Map, Value, from_reader};
+use serde_json::{Map, Value, from_str, json};
+
+/// The leaderboard to show if neither the command line nor the config file name any.
+const DEFAULT_LEADERBOARD: u64 = 70644;
 
-static ONE_DAY_IN_SECS: u64 = 24 * 60 * 60;
+/// How often `--watch` fetches each leaderboard. The server asks for no more than this.
+const WATCH_INTERVAL: Duration = Duration::from_secs(15 * 60);
 
 custom_error! { StatsError
     IOError{source: std::io::Error} = "io error",
-    Request{source: reqwest::Error} = "request error",
-    // Cache{source: std::io::Error} = "invalid cache file",
-    Time{source: SystemTimeError} = "time error",
+    Client{source: ClientError} = "{source}",
+    Session{source: SessionError} = "{source}",
     JSON{source: serde_json::error::Error} = "json error",
-    YearNotFound{year:i32} = "Year {year} not found on server.",
-    Session{source: VarError} = "Could not find AOC_SESSION env variable.",
     // CrossTerm{source: crossterm::ErrorKind} = "crossterm error",
 }
 
-#[derive(Debug)]
-struct AocStats {
-    _owner_id: String,
-    members: Vec<Member>,
-    event: String,
-}
-
-impl AocStats {
-    fn from_data(data: &Map<String, Value>) -> Self {
-        let mut members = vec![];
-        for member in data["members"].as_object().unwrap().values() {
-            members.push(Member::from_data(member.as_object().unwrap()));
-        }
-        AocStats {
-            _owner_id: data["owner_id"].to_string(),
-            members,
-            event: data["event"].as_str().unwrap().to_owned(),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Member {
-    name: String,
-    completions: [(Option<i64>, Option<i64>); 25],
-    _stars: i64,
-    _id: String,
-    _global_score: i64,
-    local_score: i64,
-    max_score: i64,
-    place: Option<i64>,
-}
-
-impl Member {
-    fn from_data(data: &Map<String, Value>) -> Self {
-        let mut completions = [(None, None); 25];
-        for (i, day_level) in completions.iter_mut().enumerate() {
-            let day = (i + 1).to_string();
-            if let Some(completion) = &data["completion_day_level"].as_object().unwrap().get(&day) {
-                let one = completion
-                    .get("1")
-                    .map(|x| x["get_star_ts"].as_i64().unwrap());
-                let two = completion
-                    .get("2")
-                    .map(|x| x["get_star_ts"].as_i64().unwrap());
-                day_level.0 = one;
-                day_level.1 = two;
-            }
+/// The different ways of looking at a leaderboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Report {
+    Scores,
+    Times,
+    Fastest,
+    Streaks,
+    History,
+    Overtakes,
+}
+
+impl Report {
+    const NAMES: [&str; 6] = [
+        "scores",
+        "times",
+        "fastest",
+        "streaks",
+        "history",
+        "overtakes",
+    ];
+
+    fn from_name(name: &str) -> Self {
+        match name {
+            "times" => Report::Times,
+            "fastest" => Report::Fastest,
+            "streaks" => Report::Streaks,
+            "history" => Report::History,
+            "overtakes" => Report::Overtakes,
+            _ => Report::Scores,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+/// What's in a column, so each format knows how to show it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Text,
+    Number,
+    /// A number of seconds.
+    Duration,
+}
+
+/// A report, ready to be printed or exported. Missing values are `Value::Null`.
+#[derive(Debug, Default)]
+struct Table {
+    columns: Vec<(String, Kind)>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    fn new(columns: &[(&str, Kind)]) -> Self {
+        Table {
+            columns: columns
+                .iter()
+                .map(|&(name, kind)| (name.to_owned(), kind))
+                .collect(),
+            rows: vec![],
+        }
+    }
+
+    /// How a cell looks in the terminal table and the CSV.
+    fn cell(value: &Value, kind: Kind) -> String {
+        match (value, kind) {
+            (Value::Null, _) => String::new(),
+            (Value::String(text), _) => text.clone(),
+            (value, Kind::Duration) => format_seconds(value.as_i64().unwrap_or_default()),
+            (value, _) => value.to_string(),
+        }
+    }
+
+    fn print(&self, stdout: &mut Stdout) -> Result<(), StatsError> {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&self.columns)
+                    .map(|(value, &(_, kind))| Table::cell(value, kind))
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| {
+                cells
+                    .iter()
+                    .map(|row| row[i].chars().count())
+                    .chain([name.chars().count()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+        let line = |row: Vec<&str>| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&self.columns)
+                .zip(&widths)
+                .map(|((cell, &(_, kind)), &width)| match kind {
+                    Kind::Text => format!("{:<width$}", cell, width = width),
+                    _ => format!("{:>width$}", cell, width = width),
+                })
+                .collect();
+            format!("  {}\n", cells.join("  ").trim_end())
+        };
+        queue!(
+            stdout,
+            SetAttribute(Attribute::Bold),
+            Print(line(
+                self.columns.iter().map(|(name, _)| name.as_str()).collect()
+            )),
+            SetAttribute(Attribute::Reset)
+        )?;
+        for row in &cells {
+            stdout.queue(Print(line(row.iter().map(String::as_str).collect())))?;
         }
+        stdout.queue(Print("\n"))?;
+        Ok(())
+    }
 
-        Member {
-            name: data["name"]
-                .as_str()
-                .unwrap_or(&format!("Anonymous User ({})", data["id"]))
-                .to_owned(),
-            completions,
-            _stars: data["stars"].as_i64().unwrap_or(0),
-            _id: data["id"].to_string(),
-            _global_score: data["global_score"].as_i64().unwrap_or(0),
-            local_score: data["local_score"].as_i64().unwrap_or(0),
-            max_score: 0,
-            place: None,
+    fn to_csv(&self) -> String {
+        let escape = |cell: String| {
+            if cell.contains([',', '"', '\n']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell
+            }
+        };
+        let mut rv = String::new();
+        let header: Vec<String> = self
+            .columns
+            .iter()
+            .map(|(name, _)| escape(name.clone()))
+            .collect();
+        rv.push_str(&(header.join(",") + "\n"));
+        for row in &self.rows {
+            // Durations stay as seconds, so they're easy to work with.
+            let row: Vec<String> = row
+                .iter()
+                .zip(&self.columns)
+                .map(|(value, &(_, kind))| match kind {
+                    Kind::Duration => escape(Table::cell(value, Kind::Number)),
+                    kind => escape(Table::cell(value, kind)),
+                })
+                .collect();
+            rv.push_str(&(row.join(",") + "\n"));
         }
+        rv
+    }
+
+    fn to_json(&self) -> Value {
+        self.rows
+            .iter()
+            .map(|row| {
+                let row: Map<String, Value> = self
+                    .columns
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .zip(row.iter().cloned())
+                    .collect();
+                Value::Object(row)
+            })
+            .collect()
     }
+}
+
+/// Show a number of seconds like `1:02:03`.
+fn format_seconds(seconds: i64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Show a number of seconds roughly, like `14m` or `2h 5m`.
+fn format_roughly(seconds: i64) -> String {
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 {
+        format!("{}m", seconds / 60)
+    } else if seconds < 86400 {
+        format!("{}h {}m", seconds / 3600, seconds / 60 % 60)
+    } else {
+        format!("{}d {}h", seconds / 86400, seconds / 3600 % 24)
+    }
+}
+
+/// Each member's score, the most they could get, and how many places they'd move up if
+/// everyone got that.
+fn scores(board: &Leaderboard) -> Vec<(&Member, i64, i64, i64)> {
+    let scores = board.scores();
+    let max_scores = board.max_scores();
+    let mut rv: Vec<usize> = board.standings();
+    let places = rv.clone();
+    rv.sort_by_key(|&i| -max_scores[i]);
+    rv.into_iter()
+        .enumerate()
+        .map(|(new_place, i)| {
+            let place = places.iter().position(|&j| j == i).unwrap_or(new_place);
+            let change = place as i64 - new_place as i64;
+            (&board.members[i], scores[i], max_scores[i], change)
+        })
+        .collect()
+}
+
+/// The names of the members ahead of each member that they could still pass.
+fn overtakes(board: &Leaderboard) -> Vec<Vec<&str>> {
+    board
+        .can_overtake()
+        .into_iter()
+        .map(|ahead| {
+            ahead
+                .into_iter()
+                .map(|i| board.members[i].name.as_str())
+                .collect()
+        })
+        .collect()
+}
 
-    fn calculate_max_score(&mut self, undone: &[(i64, i64); 25]) {
-        self.max_score += self.local_score;
-        for (remaining, completion) in undone.iter().enumerate().take(self.completions.len()) {
-            if self.completions[remaining].0.is_none() {
-                self.max_score += completion.0;
+fn report_table(report: Report, board: &Leaderboard, wide: bool) -> Table {
+    match report {
+        Report::Scores => {
+            let mut table = Table::new(&[
+                ("Member", Kind::Text),
+                ("Stars", Kind::Number),
+                ("Score", Kind::Number),
+                ("Max score", Kind::Number),
+                ("Place change", Kind::Number),
+                ("Global score", Kind::Number),
+            ]);
+            for (member, score, max_score, place) in scores(board) {
+                table.rows.push(vec![
+                    json!(member.name),
+                    json!(member.stars),
+                    json!(score),
+                    json!(max_score),
+                    json!(place),
+                    json!(member.global_score),
+                ]);
+            }
+            table
+        }
+        Report::Overtakes => {
+            let mut table = Table::new(&[
+                ("Member", Kind::Text),
+                ("Score", Kind::Number),
+                ("Max score", Kind::Number),
+                ("Can still pass", Kind::Text),
+            ]);
+            let scores = board.scores();
+            let max_scores = board.max_scores();
+            for (i, ahead) in overtakes(board).into_iter().enumerate() {
+                table.rows.push(vec![
+                    json!(board.members[i].name),
+                    json!(scores[i]),
+                    json!(max_scores[i]),
+                    json!(ahead.join(", ")),
+                ]);
             }
-            if self.completions[remaining].1.is_none() {
-                self.max_score += completion.1;
+            table
+        }
+        Report::Times => {
+            let mut table = Table::new(&[
+                ("Member", Kind::Text),
+                ("Day", Kind::Number),
+                ("Part 1", Kind::Duration),
+                ("Part 2", Kind::Duration),
+                ("Delta", Kind::Duration),
+            ]);
+            for member in &board.members {
+                for day in 1..=board.days() {
+                    let one = board.solve_time(member, day, 1);
+                    let two = board.solve_time(member, day, 2);
+                    if one.is_some() {
+                        let delta = one.zip(two).map(|(one, two)| two - one);
+                        table.rows.push(vec![
+                            json!(member.name),
+                            json!(day),
+                            json!(one),
+                            json!(two),
+                            json!(delta),
+                        ]);
+                    }
+                }
             }
+            table
+        }
+        Report::Fastest => {
+            let mut table = Table::new(&[
+                ("Day", Kind::Number),
+                ("Part 1", Kind::Text),
+                ("Part 1 time", Kind::Duration),
+                ("Part 2", Kind::Text),
+                ("Part 2 time", Kind::Duration),
+            ]);
+            for day in 1..=board.days() {
+                let one = board.fastest(day, 1);
+                let two = board.fastest(day, 2);
+                if one.is_some() {
+                    let name =
+                        |fastest: Option<(&Member, i64)>| json!(fastest.map(|(m, _)| &m.name));
+                    let time = |fastest: Option<(&Member, i64)>| json!(fastest.map(|(_, t)| t));
+                    table
+                        .rows
+                        .push(vec![json!(day), name(one), time(one), name(two), time(two)]);
+                }
+            }
+            table
+        }
+        Report::Streaks => {
+            let released = calendar::released_days(board.year, Utc::now()).len() as u32;
+            let mut table = Table::new(&[
+                ("Member", Kind::Text),
+                ("Stars", Kind::Number),
+                ("Longest streak", Kind::Number),
+                ("Current streak", Kind::Number),
+            ]);
+            for member in &board.members {
+                let (longest, current) = member.streaks(released);
+                table.rows.push(vec![
+                    json!(member.name),
+                    json!(member.stars),
+                    json!(longest),
+                    json!(current),
+                ]);
+            }
+            table
+        }
+        // One column per member reads best in the terminal, but exports need the same
+        // columns for every leaderboard, so they get one row per member per day instead.
+        Report::History if wide => {
+            let mut table = Table::new(&[("Day", Kind::Number)]);
+            for member in &board.members {
+                table.columns.push((member.name.clone(), Kind::Number));
+            }
+            for (day, scores) in board.history() {
+                table.rows.push(
+                    [json!(day)]
+                        .into_iter()
+                        .chain(scores.iter().map(|s| json!(s)))
+                        .collect(),
+                );
+            }
+            table
+        }
+        Report::History => {
+            let mut table = Table::new(&[
+                ("Day", Kind::Number),
+                ("Member", Kind::Text),
+                ("Score", Kind::Number),
+            ]);
+            for (day, scores) in board.history() {
+                for (member, score) in board.members.iter().zip(scores) {
+                    table
+                        .rows
+                        .push(vec![json!(day), json!(member.name), json!(score)]);
+                }
+            }
+            table
         }
     }
 }
 
-fn print_stats(stdout: &mut Stdout, stats: &mut AocStats) -> Result<(), StatsError> {
+fn print_title(stdout: &mut Stdout, board: &Leaderboard) -> Result<(), StatsError> {
     queue!(
         stdout,
         Print("Stats for "),
         SetForegroundColor(Color::White),
         SetAttribute(Attribute::Bold),
-        Print(stats.event.clone()),
+        Print(board.event.clone()),
         SetAttribute(Attribute::Reset),
         // ResetColor,
-        Print(":\n")
+        Print(format!(" (leaderboard {}):\n", board.owner_id))
     )?;
-    let mut undone = [(0, 0); 25];
-    for member in &stats.members {
-        for (i, undone) in undone.iter_mut().enumerate().take(member.completions.len()) {
-            if member.completions[i].0.is_none() {
-                undone.0 += 1;
-            }
-            if member.completions[i].1.is_none() {
-                undone.1 += 1;
-            }
-        }
-    }
-    stats.members.sort_by_key(|m| -m.local_score);
-    for (i, member) in stats.members.iter_mut().enumerate() {
-        member.calculate_max_score(&undone);
-        member.place = Some(i as i64);
-    }
-    stats.members.sort_by_key(|m| -m.max_score);
-    for (i, member) in stats.members.iter().enumerate() {
-        let place = member.place.unwrap() - i as i64;
+    Ok(())
+}
+
+fn print_stats(stdout: &mut Stdout, board: &Leaderboard) -> Result<(), StatsError> {
+    print_title(stdout, board)?;
+    for (member, score, max_score, place) in scores(board) {
         let place_color = match place.cmp(&0) {
             Ordering::Greater => Color::Green,
             Ordering::Less => Color::Red,
@@ -149,7 +438,7 @@ fn print_stats(stdout: &mut Stdout, stats: &mut AocStats) -> Result<(), StatsErr
             SetForegroundColor(Color::Blue),
             Print(format!("  {}: ", member.name)),
             ResetColor,
-            Print(format!("{} -> {} (", member.local_score, member.max_score)),
+            Print(format!("{} -> {} (", score, max_score)),
             SetForegroundColor(place_color),
             Print(format!("{}", place)),
             ResetColor,
@@ -160,39 +449,89 @@ fn print_stats(stdout: &mut Stdout, stats: &mut AocStats) -> Result<(), StatsErr
     Ok(())
 }
 
-fn print_year(year: i32, stdout: &mut Stdout) -> Result<(), StatsError> {
-    let cache_name = format!("stats.{}.json", year);
-    let stats_url = format!(
-        "https://adventofcode.com/{}/leaderboard/private/view/70644.json",
-        year
-    );
-    let cache = Path::new(&cache_name);
-    let now = SystemTime::now();
-    let session_cookie = format!("session={}", &var("AOC_SESSION")?);
-    if !cache.is_file()
-        || now.duration_since(cache.metadata()?.modified()?)?.as_secs() > ONE_DAY_IN_SECS
-    {
-        stdout.queue(Print("Cache doesn't exist or is too old. Downloading\n"))?;
-        let mut request = reqwest::blocking::Client::new().get(stats_url);
-        request = request.header(reqwest::header::COOKIE, session_cookie);
-
-        let mut response = request.send()?;
-        if !response.status().is_success() {
-            return Err(StatsError::YearNotFound { year });
-        }
-        // dbg!(&response.text());
-        let mut file = File::create(&cache_name)?;
-        response.copy_to(&mut file)?;
-    }
-    let cache_file = File::open(&cache_name)?;
-    let parsed_data = from_reader(cache_file);
-    if parsed_data.is_err() {
-        remove_file(cache)?;
-        return Err(StatsError::from(parsed_data.err().unwrap()));
-    }
-    let data: Map<String, Value> = parsed_data?;
-    let mut stats = AocStats::from_data(&data);
-    print_stats(stdout, &mut stats)
+/// Print a line for each star someone got between `before` and `board`, like
+/// "Alice got 12★★ in 14m".
+fn print_new_stars(
+    stdout: &mut Stdout,
+    before: &Leaderboard,
+    board: &Leaderboard,
+) -> Result<(), StatsError> {
+    for (time, i, day, part) in board.new_stars(before) {
+        let member = &board.members[i];
+        let (stars, color) = match part {
+            1 => ("★", Color::Grey),
+            _ => ("★★", Color::Yellow),
+        };
+        let when = Local
+            .timestamp_opt(time, 0)
+            .single()
+            .map_or_else(String::new, |when| when.format("%H:%M").to_string());
+        let took = board
+            .solve_time(member, day, part)
+            .map_or_else(String::new, |took| format!(" in {}", format_roughly(took)));
+        queue!(
+            stdout,
+            Print(format!("{} ", when)),
+            SetForegroundColor(Color::Blue),
+            Print(member.name.clone()),
+            ResetColor,
+            Print(format!(" got {}", day)),
+            SetForegroundColor(color),
+            Print(stars),
+            ResetColor,
+            Print(format!("{} ({})\n", took, board.event))
+        )?;
+    }
+    Ok(())
+}
+
+/// Where a leaderboard's JSON lives on the server.
+fn board_path(year: i32, board: u64) -> String {
+    format!("/{}/leaderboard/private/view/{}.json", year, board)
+}
+
+fn parse_board(text: &str) -> Result<Leaderboard, StatsError> {
+    let data: Map<String, Value> = from_str(text)?;
+    Ok(Leaderboard::from_data(&data))
+}
+
+fn load_year(
+    client: &Client,
+    max_age: Duration,
+    board: u64,
+    year: i32,
+    chatty: bool,
+) -> Result<Leaderboard, StatsError> {
+    let stats_path = board_path(year, board);
+    if chatty && !client.is_cached(&stats_path, max_age) && client.account().is_some() {
+        println!("Cache doesn't exist or is too old. Downloading");
+    }
+    let board = parse_board(&client.get_cached(&stats_path, max_age)?);
+    if board.is_err() {
+        client.uncache(&stats_path)?;
+    }
+    board
+}
+
+/// Load a leaderboard saved from the server's JSON API.
+fn load_file(path: &PathBuf) -> Result<Leaderboard, StatsError> {
+    parse_board(&read_to_string(path)?)
+}
+
+fn print_error(stdout: &mut Stdout, format: Format, error: String) -> Result<(), StatsError> {
+    if format == Format::Table {
+        queue!(
+            stdout,
+            SetForegroundColor(Color::Red),
+            Print(error),
+            ResetColor,
+            Print("\n")
+        )?;
+    } else {
+        // Keep the export itself clean.
+        eprintln!("{}", error);
+    }
+    Ok(())
 }
 
 fn main() -> Result<(), StatsError> {
@@ -209,36 +548,328 @@ fn main() -> Result<(), StatsError> {
                 )
                 .index(1)
                 .num_args(0..)
-                .default_value("*"),
+                .value_parser(value_parser!(i32)),
+        )
+        .arg(
+            Arg::new("max_age")
+                .short('m')
+                .long("max-age")
+                .help("How old the cached leaderboards can get before they're downloaded again")
+                .long_help(
+                    "How old the cached leaderboards can get before they're downloaded again,
+ like `15m` or `1day`. The server asks that they're fetched no more than every 15 minutes.",
+                )
+                .value_name("age")
+                .value_parser(value_parser!(humantime::Duration))
+                .default_value("1day"),
+        )
+        .arg(
+            Arg::new("account")
+                .short('u')
+                .long("account")
+                .help("Use another account's session")
+                .long_help("Use the session for this account from the config file.")
+                .value_name("name"),
+        )
+        .arg(
+            Arg::new("board")
+                .short('b')
+                .long("board")
+                .help("Which private leaderboard(s) to show")
+                .long_help(
+                    "The IDs of the private leaderboards to show, from the end of their URLs.
+ Without any, the \"leaderboards\" from the config file are shown.",
+                )
+                .value_name("id")
+                .value_parser(value_parser!(u64))
+                .num_args(1..)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("offline")
+                .short('o')
+                .long("offline")
+                .help("Only use the cached leaderboards")
+                .long_help(
+                    "Don't download anything, and show the cached leaderboards no matter how old they are.",
+                )
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("file")
+                .short('f')
+                .long("file")
+                .help("Show leaderboards saved as JSON")
+                .long_help(
+                    "Show leaderboards saved from the server's JSON API, instead of fetching them.",
+                )
+                .value_name("json")
+                .value_parser(value_parser!(PathBuf))
+                .num_args(1..)
+                .action(ArgAction::Append)
+                .conflicts_with_all(["year", "board", "offline", "account"]),
+        )
+        .arg(
+            Arg::new("report")
+                .short('r')
+                .long("report")
+                .help("What to show")
+                .long_help(
+                    "What to show: everyone's scores and the most they could get, how long each star
+ took after the puzzle unlocked, who was fastest each day, everyone's streaks of days with
+ both stars, how the scores changed day by day, or who can still overtake whom.",
+                )
+                .value_parser(Report::NAMES)
+                .default_value("scores"),
+        )
+        .arg(
+            Arg::new("format")
+                .short('t')
+                .long("format")
+                .help("How to show it")
+                .long_help(
+                    "Show the report as a table in the terminal, or export it as CSV or JSON,
+ with times in seconds.",
+                )
+                .value_parser(["table", "csv", "json"])
+                .default_value("table"),
+        )
+        .arg(
+            Arg::new("watch")
+                .short('w')
+                .long("watch")
+                .help("Keep checking for new stars")
+                .long_help(
+                    "Keep fetching the leaderboards, and print each new star as it turns up.
+ They're fetched every 15 minutes, or every --max-age if that's longer. Without a year,
+ only this year's event is watched.",
+                )
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["file", "offline", "report", "format"]),
         )
         .get_matches();
 
-    let args: Vec<&String> = matches.get_many("year").unwrap().collect();
-    let today = Local::now().date_naive();
-    let this_year = today.year();
+    if matches.get_flag("watch") {
+        return watch(&matches, &mut stdout);
+    }
+
+    let report = Report::from_name(matches.get_one::<String>("report").unwrap());
+    let format = match matches.get_one::<String>("format").unwrap().as_str() {
+        "csv" => Format::Csv,
+        "json" => Format::Json,
+        _ => Format::Table,
+    };
+
+    let mut boards = vec![];
+    if let Some(paths) = matches.get_many::<PathBuf>("file") {
+        for path in paths {
+            match load_file(path) {
+                Ok(board) => boards.push(board),
+                Err(error) => print_error(&mut stdout, format, format!("{:?}: {}", path, error))?,
+            }
+        }
+    } else {
+        load_boards(&matches, format, &mut boards, &mut stdout)?;
+    }
 
-    let args: Vec<i32> = args
-        .iter()
-        .flat_map(|&x| {
-            if x == "*" {
-                2015..=this_year
+    if format == Format::Table {
+        for board in &boards {
+            if report == Report::Scores {
+                print_stats(&mut stdout, board)?;
             } else {
-                x.parse().unwrap()..=x.parse().unwrap()
+                print_title(&mut stdout, board)?;
+                report_table(report, board, true).print(&mut stdout)?;
             }
-        })
-        .collect();
-    stdout.execute(Print(format!("args: {:?}\n", args)))?;
+        }
+    } else {
+        // Everything goes in one export, with the leaderboard each row came from.
+        let mut export = Table::new(&[("Year", Kind::Number), ("Leaderboard", Kind::Text)]);
+        for board in &boards {
+            let table = report_table(report, board, false);
+            if export.columns.len() == 2 {
+                export.columns.extend(table.columns);
+            }
+            for row in table.rows {
+                let from = [json!(board.year), json!(board.owner_id)];
+                export.rows.push(from.into_iter().chain(row).collect());
+            }
+        }
+        match format {
+            Format::Csv => stdout.queue(Print(export.to_csv()))?,
+            _ => stdout.queue(Print(
+                serde_json::to_string_pretty(&export.to_json())? + "\n",
+            ))?,
+        };
+    }
+    stdout.flush()?;
+    Ok(())
+}
 
+/// Fetch (or find in the cache) the leaderboards for the years and boards on the command line.
+fn load_boards(
+    matches: &ArgMatches,
+    format: Format,
+    boards: &mut Vec<Leaderboard>,
+    stdout: &mut Stdout,
+) -> Result<(), StatsError> {
+    let args = years(matches);
+    if format == Format::Table {
+        stdout.execute(Print(format!("args: {:?}\n", args)))?;
+    }
+
+    let ids = board_ids(matches)?;
+    let (client, max_age) = if matches.get_flag("offline") {
+        (Client::offline(), Duration::MAX)
+    } else {
+        (online_client(matches)?, max_age(matches))
+    };
     for year in args {
-        if let Err(error) = print_year(year, &mut stdout) {
-            queue!(
-                stdout,
-                SetForegroundColor(Color::Red),
-                Print(format!("{}", error)),
-                ResetColor
-            )?;
-        };
-        stdout.flush()?;
+        for &id in &ids {
+            match load_year(&client, max_age, id, year, format == Format::Table) {
+                Ok(board) => boards.push(board),
+                Err(error) => print_error(stdout, format, error.to_string())?,
+            }
+            stdout.flush()?;
+        }
     }
     Ok(())
 }
+
+/// The years on the command line, or else every year up to the latest puzzle.
+fn years(matches: &ArgMatches) -> Vec<i32> {
+    match matches.get_many::<i32>("year") {
+        Some(years) => years.copied().collect(),
+        None => (FIRST_YEAR..=calendar::latest_puzzle(Utc::now()).0).collect(),
+    }
+}
+
+/// The leaderboards on the command line, or else the ones from the config file.
+fn board_ids(matches: &ArgMatches) -> Result<Vec<u64>, StatsError> {
+    Ok(match matches.get_many::<u64>("board") {
+        Some(boards) => boards.copied().collect(),
+        None => match Accounts::load()?.leaderboards() {
+            [] => vec![DEFAULT_LEADERBOARD],
+            boards => boards.to_vec(),
+        },
+    })
+}
+
+fn online_client(matches: &ArgMatches) -> Result<Client, StatsError> {
+    let account = matches.get_one::<String>("account").map(String::as_str);
+    Ok(Client::for_account(account)?)
+}
+
+fn max_age(matches: &ArgMatches) -> Duration {
+    (*matches.get_one::<humantime::Duration>("max_age").unwrap()).into()
+}
+
+/// Fetch the leaderboards every so often, forever, printing the stars that are new since the
+/// last time. The first time round, that's everything since they were last cached.
+fn watch(matches: &ArgMatches, stdout: &mut Stdout) -> Result<(), StatsError> {
+    let years = if matches.contains_id("year") {
+        years(matches)
+    } else {
+        vec![calendar::latest_puzzle(Utc::now()).0]
+    };
+    let interval = match matches.value_source("max_age") {
+        Some(ValueSource::CommandLine) => max_age(matches).max(WATCH_INTERVAL),
+        _ => WATCH_INTERVAL,
+    };
+    let ids = board_ids(matches)?;
+    let client = online_client(matches)?;
+    let mut first = true;
+    loop {
+        for &year in &years {
+            for &id in &ids {
+                let before = client
+                    .cached(&board_path(year, id))
+                    .and_then(|text| parse_board(&text).ok());
+                match load_year(&client, interval, id, year, false) {
+                    Ok(board) => {
+                        if first {
+                            print_stats(stdout, &board)?;
+                        }
+                        if let Some(before) = before {
+                            print_new_stars(stdout, &before, &board)?;
+                        }
+                    }
+                    Err(error) => print_error(stdout, Format::Table, error.to_string())?,
+                }
+                stdout.flush()?;
+            }
+        }
+        first = false;
+        sleep(interval);
+    }
+}
+
+#[test]
+fn test_reports() {
+    use pretty_assertions::assert_eq;
+
+    // 2024's day one unlocked at 1733029200, and day two a day after that.
+    let board = parse_board(
+        r#"{"owner_id": 123, "event": "2024", "members": {
+            "1": {"id": 1, "name": "Smith, \"Ace\"", "stars": 3, "local_score": 5,
+                "global_score": 0, "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1733029260}, "2": {"get_star_ts": 1733029500}},
+                    "2": {"1": {"get_star_ts": 1733119200}}
+                }},
+            "2": {"id": 2, "name": "Bo", "stars": 2, "local_score": 3, "global_score": 0,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1733029230}, "2": {"get_star_ts": 1733033200}}
+                }}
+        }}"#,
+    )
+    .unwrap();
+    let csv = |report| report_table(report, &board, false).to_csv();
+
+    assert_eq!(
+        csv(Report::Times),
+        "Member,Day,Part 1,Part 2,Delta
+\"Smith, \"\"Ace\"\"\",1,60,300,240
+\"Smith, \"\"Ace\"\"\",2,3600,,
+Bo,1,30,4000,3970
+"
+    );
+    assert_eq!(
+        csv(Report::Fastest),
+        "Day,Part 1,Part 1 time,Part 2,Part 2 time
+1,Bo,30,\"Smith, \"\"Ace\"\"\",300
+2,\"Smith, \"\"Ace\"\"\",3600,,
+"
+    );
+    assert_eq!(
+        csv(Report::Streaks),
+        "Member,Stars,Longest streak,Current streak
+\"Smith, \"\"Ace\"\"\",3,1,0
+Bo,2,1,0
+"
+    );
+    assert_eq!(
+        csv(Report::History),
+        "Day,Member,Score
+1,\"Smith, \"\"Ace\"\"\",3
+1,Bo,3
+2,\"Smith, \"\"Ace\"\"\",5
+2,Bo,3
+"
+    );
+
+    let history = report_table(Report::History, &board, true);
+    assert_eq!(
+        history.to_json(),
+        json!([
+            {"Day": 1, "Smith, \"Ace\"": 3, "Bo": 3},
+            {"Day": 2, "Smith, \"Ace\"": 5, "Bo": 3},
+        ])
+    );
+    assert_eq!(
+        report_table(Report::Times, &board, false).to_json()[1],
+        json!({"Member": "Smith, \"Ace\"", "Day": 2, "Part 1": 3600, "Part 2": null, "Delta": null})
+    );
+
+    assert_eq!(format_seconds(3723), "1:02:03");
+    assert_eq!(format_seconds(59), "0:00:59");
+    assert_eq!(Table::cell(&json!(90061), Kind::Duration), "25:01:01");
+}
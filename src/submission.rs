@@ -0,0 +1,250 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use chrono::Utc;
+use custom_error::custom_error;
+use serde_json::{Value, json};
+
+use crate::{Part, input::data_path, puzzle::parse_puzzle, regex};
+
+custom_error! { pub SubmissionError
+    IOError{source: std::io::Error} = "io error",
+    JSON{source: serde_json::error::Error} = "json error",
+}
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer wasn't checked, because the last one was too recent.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The answer wasn't checked, because the part is already solved (or not unlocked yet).
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    /// Whether the server actually judged the answer, and so whether it's worth remembering.
+    pub fn is_judged(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+        )
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too high" => Some(Outcome::TooHigh),
+            "too low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => f.write_str("correct"),
+            Outcome::TooHigh => f.write_str("too high"),
+            Outcome::TooLow => f.write_str("too low"),
+            Outcome::Wrong => f.write_str("wrong"),
+            Outcome::RateLimited { wait } => write!(f, "rate limited for {}s", wait.as_secs()),
+            Outcome::AlreadySolved => f.write_str("already solved"),
+            Outcome::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+/// Work out what the page returned by `/{year}/day/{day}/answer` means.
+pub fn parse_response(html: &str) -> Outcome {
+    let text: String = parse_puzzle(html)
+        .into_iter()
+        .map(|part| part.markdown)
+        .collect();
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = regex!(r"You have (?:(\d+)m )?(\d+)s left to wait")
+            .captures(&text)
+            .map_or(0, |captures| {
+                let number = |i| {
+                    captures
+                        .get(i)
+                        .map_or(0, |m| m.as_str().parse().unwrap_or(0))
+                };
+                number(1) * 60 + number(2)
+            });
+        Outcome::RateLimited {
+            wait: Duration::from_secs(wait),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown
+    }
+}
+
+/// The answers submitted for a year, stored as `attempts.json` next to the year's inputs,
+/// so that we never send the server an answer it's already told us is wrong.
+#[derive(Debug)]
+pub struct Attempts {
+    path: PathBuf,
+    attempts: BTreeMap<String, Vec<Value>>,
+}
+
+impl Attempts {
+    pub fn path(year: u32) -> PathBuf {
+        data_path(&year.to_string(), "attempts.json")
+    }
+
+    /// Load the attempts for `year`, or an empty log if nothing has been submitted yet.
+    pub fn load(year: u32) -> Result<Self, SubmissionError> {
+        let path = Attempts::path(year);
+        let attempts = if path.is_file() {
+            serde_json::from_reader(BufReader::new(File::open(&path)?))?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Attempts { path, attempts })
+    }
+
+    fn key(day: u32, part: Part) -> String {
+        format!("{}{}", day, part).to_lowercase()
+    }
+
+    /// The judged answers for a part, oldest first.
+    pub fn get(&self, day: u32, part: Part) -> Vec<(String, Outcome)> {
+        self.attempts
+            .get(&Attempts::key(day, part))
+            .into_iter()
+            .flatten()
+            .filter_map(|attempt| {
+                let answer = attempt["answer"].as_str()?.to_owned();
+                let outcome = Outcome::from_name(attempt["outcome"].as_str()?)?;
+                Some((answer, outcome))
+            })
+            .collect()
+    }
+
+    /// What we already know about `answer`, either from submitting it before,
+    /// or because it's past an earlier answer that was too high or too low.
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Option<Outcome> {
+        let number = answer.parse::<i128>().ok();
+        self.get(day, part)
+            .into_iter()
+            .find_map(|(previous, outcome)| {
+                let bound = previous.parse::<i128>().ok();
+                let known = match (&outcome, number, bound) {
+                    _ if previous == answer => true,
+                    (Outcome::TooHigh, Some(number), Some(bound)) => number >= bound,
+                    (Outcome::TooLow, Some(number), Some(bound)) => number <= bound,
+                    _ => false,
+                };
+                known.then_some(outcome)
+            })
+    }
+
+    /// Remember the outcome of submitting `answer`, if the server judged it.
+    pub fn record(&mut self, day: u32, part: Part, answer: &str, outcome: &Outcome) {
+        if !outcome.is_judged() {
+            return;
+        }
+        self.attempts
+            .entry(Attempts::key(day, part))
+            .or_default()
+            .push(json!({
+                "answer": answer,
+                "outcome": outcome.to_string(),
+                "time": Utc::now().to_rfc3339(),
+            }));
+    }
+
+    pub fn save(&self) -> Result<(), SubmissionError> {
+        let mut writer = BufWriter::new(File::create(&self.path)?);
+        serde_json::to_writer_pretty(&mut writer, &self.attempts)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_submission() {
+    use pretty_assertions::assert_eq;
+
+    let page = |message: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", message);
+    assert_eq!(
+        parse_response(&page(
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+        )),
+        Outcome::Correct
+    );
+    assert_eq!(
+        parse_response(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data."
+        )),
+        Outcome::TooHigh
+    );
+    assert_eq!(
+        parse_response(&page(
+            "That's not the right answer.  If you're stuck, there are some general tips on the <a href=\"/2025/about\">about page</a>."
+        )),
+        Outcome::Wrong
+    );
+    assert_eq!(
+        parse_response(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2025/day/1\">[Return to Day 1]</a>"
+        )),
+        Outcome::RateLimited {
+            wait: Duration::from_secs(65)
+        }
+    );
+    assert_eq!(
+        parse_response(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        )),
+        Outcome::AlreadySolved
+    );
+
+    let mut attempts = Attempts {
+        path: PathBuf::new(),
+        attempts: BTreeMap::new(),
+    };
+    attempts.record(1, Part::A, "100", &Outcome::TooHigh);
+    attempts.record(1, Part::A, "10", &Outcome::TooLow);
+    attempts.record(1, Part::A, "abc", &Outcome::Wrong);
+    attempts.record(
+        1,
+        Part::A,
+        "50",
+        &Outcome::RateLimited {
+            wait: Duration::ZERO,
+        },
+    );
+    assert_eq!(attempts.get(1, Part::A).len(), 3);
+    assert_eq!(attempts.check(1, Part::A, "abc"), Some(Outcome::Wrong));
+    assert_eq!(attempts.check(1, Part::A, "150"), Some(Outcome::TooHigh));
+    assert_eq!(attempts.check(1, Part::A, "7"), Some(Outcome::TooLow));
+    assert_eq!(attempts.check(1, Part::A, "50"), None);
+    assert_eq!(attempts.check(1, Part::B, "abc"), None);
+}
@@ -1,4 +1,10 @@
-use std::fmt::Display;
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    iter::repeat_n,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
 
 pub type Point2 = (i64, i64);
 pub type Point3 = (i64, i64, i64);
@@ -120,3 +126,303 @@ impl Display for Direction {
         }
     }
 }
+
+/// A rectangular grid of cells, indexed by `(x, y)` points with `(0, 0)` in the top left.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid is the wrong size!");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse the puzzle text one line per row, converting each character with `cell`.
+    /// Rows shorter than the longest one are padded out as if they ended in spaces,
+    /// since some puzzles' maps don't bother with their trailing blanks.
+    /// Blank lines before and after the grid are skipped, but one in the middle panics,
+    /// since it probably means the input is more than one block and needs splitting first.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let lines: Vec<&str> = input.lines().collect();
+        let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(0);
+        let end = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(0, |end| end + 1);
+        let lines = &lines[start..end.max(start)];
+        assert!(
+            !lines.contains(&""),
+            "Grid has a blank line in the middle of it!"
+        );
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in lines {
+            let padding = width - line.chars().count();
+            cells.extend(line.chars().chain(repeat_n(' ', padding)).map(&mut cell));
+        }
+        Grid::new(width, lines.len(), cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The point just past the bottom right corner, for use with `in_bounds` and `move_pos`.
+    pub fn bounds(&self) -> Point2 {
+        (self.width as i64, self.height as i64)
+    }
+
+    pub fn in_bounds(&self, point: Point2) -> bool {
+        in_bounds(point, (0, 0), self.bounds())
+    }
+
+    pub fn get(&self, point: Point2) -> Option<&T> {
+        if self.in_bounds(point) {
+            Some(&self.cells[point_to_index(point, self.width as i64)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point2) -> Option<&mut T> {
+        if self.in_bounds(point) {
+            Some(&mut self.cells[point_to_index(point, self.width as i64)])
+        } else {
+            None
+        }
+    }
+
+    /// Replace the cell at `point`, returning the old value, or `None` if it's out of bounds.
+    pub fn set(&mut self, point: Point2, value: T) -> Option<T> {
+        self.get_mut(point)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point2> + use<T> {
+        let (width, height) = self.bounds();
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, in reading order, whose cell matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The in-bounds points to the north, east, south, and west of `point`.
+    pub fn neighbours(&self, point: Point2) -> impl Iterator<Item = Point2> + use<T> {
+        let bounds = self.bounds();
+        Direction::all()
+            .into_iter()
+            .filter_map(move |direction| direction.move_pos(point, 1, Some((0, 0)), Some(bounds)))
+    }
+
+    /// The in-bounds points surrounding `point`, including the diagonals.
+    pub fn neighbours8(&self, point: Point2) -> impl Iterator<Item = Point2> + use<T> {
+        let bounds = self.bounds();
+        Direction::all()
+            .into_iter()
+            .flat_map(move |direction| {
+                let (x, y) = direction.get_point();
+                let (dx, dy) = direction.turn_right().get_point();
+                [
+                    (point.0 + x, point.1 + y),
+                    (point.0 + x + dx, point.1 + y + dy),
+                ]
+            })
+            .filter(move |&neighbour| in_bounds(neighbour, (0, 0), bounds))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(cell).collect(),
+        )
+    }
+
+    /// Build a new grid of size `width` x `height`, taking each cell from `source(x, y)` in this one.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Point2) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+        Grid::new(width, height, cells)
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y as i64, x as i64))
+    }
+
+    /// Rotate the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| {
+            (y as i64, (height - 1 - x) as i64)
+        })
+    }
+
+    /// Rotate the grid a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| {
+            ((width - 1 - y) as i64, x as i64)
+        })
+    }
+
+    /// Mirror the grid left-to-right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |x, y| {
+            ((width - 1 - x) as i64, y as i64)
+        })
+    }
+
+    /// Mirror the grid top-to-bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, |x, y| {
+            (x as i64, (height - 1 - y) as i64)
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, vec![value; width * height])
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Grid::parse(s, |c| c))
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid!", point))
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, point: Point2) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid!", point))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid() {
+    use pretty_assertions::assert_eq;
+
+    let grid: Grid<char> = "ab\ncd\nef\n".parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid.get((1, 2)), Some(&'f'));
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.find(|&c| c == 'd'), Some((1, 1)));
+    assert_eq!(grid.row(1), &['c', 'd']);
+    assert_eq!(grid.column(1).collect::<String>(), "bdf");
+    assert_eq!(
+        grid.neighbours((0, 0)).collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+
+    assert_eq!(grid.rotate_right().to_string(), "eca\nfdb\n");
+    assert_eq!(grid.rotate_left().to_string(), "bdf\nace\n");
+    assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+    assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe\n");
+    assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab\n");
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+
+    let mut grid = grid;
+    assert_eq!(grid.set((0, 0), 'z'), Some('a'));
+    assert_eq!(grid.set((5, 5), 'z'), None);
+    assert_eq!(grid[(0, 0)], 'z');
+
+    // Trailing spaces are kept, and short rows padded out with more.
+    let grid: Grid<char> = "\n  a  \nb\n c \n\n".parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (5, 3));
+    assert_eq!(grid.to_string(), "  a  \nb    \n c   \n");
+}
+
+#[test]
+#[should_panic(expected = "blank line in the middle")]
+fn test_grid_blocks() {
+    let _: Grid<char> = "#.#\n...\n\n<>^v\n".parse().unwrap();
+}
@@ -1,7 +1,5 @@
 #![feature(exact_size_is_empty)]
 
-// modules
-
 #[macro_use]
 extern crate aoc;
 #[macro_use]
@@ -12,8 +10,9 @@ extern crate indoc;
 #[macro_use]
 extern crate itertools;
 
-fn main() {
-    let days = q_vec!();
+#[path = "mod.rs"]
+mod year;
 
-    aoc::main(&days)
+fn main() {
+    aoc::main(XXXX, &year::days())
 }
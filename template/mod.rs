@@ -0,0 +1,5 @@
+// modules
+
+pub fn days() -> Vec<Box<dyn aoc::Day>> {
+    q_vec!()
+}
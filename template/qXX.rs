@@ -1,7 +1,7 @@
 //-----------------------------------------------------
 // Setup.
 
-static INPUT: &str = include_str!("data/qXX.data");
+input!("qXX");
 
 fn process_data_a(data: &str) -> usize {
     let rv = 0;